@@ -0,0 +1,97 @@
+package Util;
+
+import java.nio.ByteBuffer;
+
+// Byte-oriented ring with partial reads and writes. asSlices() exposes the
+// buffered bytes as at most two read-only views so parsers can work in place.
+public class ByteRing {
+    private final byte[] data;
+    private int head;
+    private int size;
+
+    public ByteRing(int capacity){
+        if (capacity <= 0){
+            throw new IllegalArgumentException("Capacity must be positive : " + capacity);
+        }
+        this.data = new byte[capacity];
+    }
+
+    // Writes as much as fits and returns the number of bytes accepted.
+    public int write(byte[] src, int off, int len){
+        int n = Math.min(len, data.length - size);
+        int tail = (head + size) % data.length;
+        int first = Math.min(n, data.length - tail);
+        System.arraycopy(src, off, data, tail, first);
+        System.arraycopy(src, off + first, data, 0, n - first);
+        size += n;
+        return n;
+    }
+
+    public int write(byte[] src){
+        return write(src, 0, src.length);
+    }
+
+    public boolean write(byte b){
+        if (size == data.length){
+            return false;
+        }
+        data[(head + size) % data.length] = b;
+        size++;
+        return true;
+    }
+
+    public int read(byte[] dst, int off, int len){
+        int n = Math.min(len, size);
+        int first = Math.min(n, data.length - head);
+        System.arraycopy(data, head, dst, off, first);
+        System.arraycopy(data, 0, dst, off + first, n - first);
+        consume(n);
+        return n;
+    }
+
+    public int read(byte[] dst){
+        return read(dst, 0, dst.length);
+    }
+
+    public int read(){
+        if (size == 0){
+            return -1;
+        }
+        int b = data[head] & 0xFF;
+        consume(1);
+        return b;
+    }
+
+    public ByteBuffer[] asSlices(){
+        int first = Math.min(size, data.length - head);
+        return new ByteBuffer[]{
+                ByteBuffer.wrap(data, head, first).slice().asReadOnlyBuffer(),
+                ByteBuffer.wrap(data, 0, size - first).slice().asReadOnlyBuffer()
+        };
+    }
+
+    public void consume(int n){
+        if (n < 0 || n > size){
+            throw new IllegalArgumentException("Cannot consume " + n + " of " + size + " bytes");
+        }
+        head = (head + n) % data.length;
+        size -= n;
+    }
+
+    public int len(){
+        return size;
+    }
+
+    public int free(){
+        return data.length - size;
+    }
+
+    public int capacity(){
+        return data.length;
+    }
+
+    public void clear(){
+        head = 0;
+        size = 0;
+    }
+}
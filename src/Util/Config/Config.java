@@ -0,0 +1,473 @@
+package Util.Config;
+
+import java.util.ArrayList;
+import java.util.Collections;
+import java.util.LinkedHashMap;
+import java.util.List;
+import java.util.Map;
+
+// Settings tree read from a small TOML-like format:
+//
+//   # comment
+//   [nav.pid.altitude]
+//   kp = 1.5
+//   name = "baro"
+//   enabled = true
+//   gains = [1, 2.5, 3]
+//
+// Values are stored flat under their dotted path. Integers are kept as Long,
+// floats as Double; an integer may be read as a float but not the other way.
+public class Config {
+    public static final String ENV_PREFIX = "BRUNA_";
+
+    private final Map<String, Object> values = new LinkedHashMap<String, Object>();
+
+    public Config(){}
+
+    public static Config parse(String text) throws ConfigException {
+        Config config = new Config();
+        List<String> problems = new ArrayList<String>();
+        String section = "";
+        String[] lines = text.split("\r?\n", -1);
+        for (int i = 0; i < lines.length; i++){
+            String line = stripComment(lines[i]).trim();
+            int lineNo = i + 1;
+            if (line.isEmpty()){
+                continue;
+            }
+            if (line.startsWith("[")){
+                if (!line.endsWith("]") || !isPath(line.substring(1, line.length() - 1).trim())){
+                    problems.add("line " + lineNo + ": bad section header '" + line + "'");
+                    continue;
+                }
+                section = line.substring(1, line.length() - 1).trim();
+                continue;
+            }
+            int eq = line.indexOf('=');
+            if (eq < 0){
+                problems.add("line " + lineNo + ": expected key = value");
+                continue;
+            }
+            String key = line.substring(0, eq).trim();
+            if (!isPath(key)){
+                problems.add("line " + lineNo + ": bad key '" + key + "'");
+                continue;
+            }
+            String path = section.isEmpty() ? key : section + "." + key;
+            try {
+                config.values.put(path, parseValue(line.substring(eq + 1).trim()));
+            } catch (IllegalArgumentException e){
+                problems.add("line " + lineNo + ": " + e.getMessage());
+            }
+        }
+        if (!problems.isEmpty()){
+            throw new ConfigException(problems);
+        }
+        return config;
+    }
+
+    // Sub-tree under prefix, with the prefix stripped from its keys.
+    public Config section(String prefix){
+        Config sub = new Config();
+        for (Map.Entry<String, Object> entry : values.entrySet()){
+            if (entry.getKey().startsWith(prefix + ".")){
+                sub.values.put(entry.getKey().substring(prefix.length() + 1), entry.getValue());
+            }
+        }
+        return sub;
+    }
+
+    public boolean contains(String path){
+        return values.containsKey(path);
+    }
+
+    public Object get(String path){
+        return values.get(path);
+    }
+
+    public void set(String path, Object value){
+        values.put(path, normalize(value));
+    }
+
+    public Map<String, Object> asMap(){
+        return Collections.unmodifiableMap(values);
+    }
+
+    public String getString(String path){
+        return (String) typed(path, String.class);
+    }
+
+    public long getLong(String path){
+        return (Long) typed(path, Long.class);
+    }
+
+    public int getInt(String path){
+        long value = getLong(path);
+        if (value < Integer.MIN_VALUE || value > Integer.MAX_VALUE){
+            throw new IllegalStateException(path + " is out of int range : " + value);
+        }
+        return (int) value;
+    }
+
+    public double getDouble(String path){
+        return (Double) typed(path, Double.class);
+    }
+
+    public float getFloat(String path){
+        return (float) getDouble(path);
+    }
+
+    public boolean getBool(String path){
+        return (Boolean) typed(path, Boolean.class);
+    }
+
+    @SuppressWarnings("unchecked")
+    public List<Object> getList(String path){
+        return (List<Object>) typed(path, List.class);
+    }
+
+    public String getString(String path, String def){ return contains(path) ? getString(path) : def;}
+
+    public long getLong(String path, long def){ return contains(path) ? getLong(path) : def;}
+
+    public int getInt(String path, int def){ return contains(path) ? getInt(path) : def;}
+
+    public double getDouble(String path, double def){ return contains(path) ? getDouble(path) : def;}
+
+    public float getFloat(String path, float def){ return contains(path) ? getFloat(path) : def;}
+
+    public boolean getBool(String path, boolean def){ return contains(path) ? getBool(path) : def;}
+
+    // BRUNA_NAV__PID__ALTITUDE__KP=2.0 overrides nav.pid.altitude.kp. Values
+    // are parsed like file values; anything that does not parse is a string.
+    // Environment names are upper case, so the path is matched without regard
+    // to case against the schema's keys and the keys already present, and
+    // lower-cased when neither has it.
+    public void applyEnv(Map<String, String> env, Schema schema){
+        for (Map.Entry<String, String> entry : env.entrySet()){
+            if (!entry.getKey().startsWith(ENV_PREFIX)){
+                continue;
+            }
+            String path = entry.getKey().substring(ENV_PREFIX.length()).replace("__", ".");
+            if (!isPath(path)){
+                continue;
+            }
+            path = resolveKey(path, schema);
+            Object value;
+            try {
+                value = parseValue(entry.getValue().trim());
+            } catch (IllegalArgumentException e){
+                value = entry.getValue();
+            }
+            values.put(path, value);
+        }
+    }
+
+    public void applyEnv(Map<String, String> env){
+        applyEnv(env, null);
+    }
+
+    public void applyEnv(Schema schema){
+        applyEnv(System.getenv(), schema);
+    }
+
+    public void applyEnv(){
+        applyEnv(System.getenv(), null);
+    }
+
+    private String resolveKey(String path, Schema schema){
+        if (schema != null){
+            for (String key : schema.types.keySet()){
+                if (key.equalsIgnoreCase(path)){
+                    return key;
+                }
+            }
+        }
+        for (String key : values.keySet()){
+            if (key.equalsIgnoreCase(path)){
+                return key;
+            }
+        }
+        return path.toLowerCase();
+    }
+
+    // Fills in defaults and returns every missing or mistyped key at once.
+    public List<String> validate(Schema schema){
+        List<String> problems = new ArrayList<String>();
+        for (Map.Entry<String, Object> entry : schema.defaults.entrySet()){
+            if (!values.containsKey(entry.getKey())){
+                values.put(entry.getKey(), entry.getValue());
+            }
+        }
+        for (Map.Entry<String, Class<?>> entry : schema.types.entrySet()){
+            String path = entry.getKey();
+            if (!values.containsKey(path)){
+                if (schema.required.contains(path)){
+                    problems.add(path + ": required key is missing");
+                }
+                continue;
+            }
+            if (coerce(values.get(path), entry.getValue()) == null){
+                problems.add(path + ": expected " + typeName(entry.getValue()) + ", found " + typeName(values.get(path).getClass()));
+            }
+        }
+        return problems;
+    }
+
+    // Writes the tree back out grouped by section; parse(serialize()) gives
+    // back the same values.
+    public String serialize(){
+        Map<String, List<String>> sections = new LinkedHashMap<String, List<String>>();
+        for (Map.Entry<String, Object> entry : values.entrySet()){
+            String path = entry.getKey();
+            int dot = path.lastIndexOf('.');
+            String section = dot < 0 ? "" : path.substring(0, dot);
+            List<String> lines = sections.get(section);
+            if (lines == null){
+                lines = new ArrayList<String>();
+                sections.put(section, lines);
+            }
+            lines.add(path.substring(dot + 1) + " = " + format(entry.getValue()));
+        }
+        StringBuilder out = new StringBuilder();
+        List<String> top = sections.remove("");
+        if (top != null){
+            for (String line : top){
+                out.append(line).append('\n');
+            }
+        }
+        for (Map.Entry<String, List<String>> entry : sections.entrySet()){
+            if (out.length() > 0){
+                out.append('\n');
+            }
+            out.append('[').append(entry.getKey()).append("]\n");
+            for (String line : entry.getValue()){
+                out.append(line).append('\n');
+            }
+        }
+        return out.toString();
+    }
+
+    @Override
+    public String toString(){
+        return serialize();
+    }
+
+    public static class Schema {
+        private final Map<String, Class<?>> types = new LinkedHashMap<String, Class<?>>();
+        private final Map<String, Object> defaults = new LinkedHashMap<String, Object>();
+        private final List<String> required = new ArrayList<String>();
+
+        public Schema require(String path, Class<?> type){
+            types.put(path, type);
+            required.add(path);
+            return this;
+        }
+
+        // A null default declares the key without a default or a type check.
+        public Schema optional(String path, Object def){
+            if (def == null){
+                types.put(path, Object.class);
+                return this;
+            }
+            Object value = normalize(def);
+            types.put(path, value instanceof List ? List.class : value.getClass());
+            defaults.put(path, value);
+            return this;
+        }
+    }
+
+    private Object typed(String path, Class<?> type){
+        Object value = values.get(path);
+        if (value == null){
+            throw new IllegalStateException("Missing config key : " + path);
+        }
+        Object coerced = coerce(value, type);
+        if (coerced == null){
+            throw new IllegalStateException(path + " : expected " + typeName(type) + ", found " + typeName(value.getClass()));
+        }
+        return coerced;
+    }
+
+    private static Object coerce(Object value, Class<?> type){
+        if (type == Double.class && value instanceof Long){
+            return ((Long) value).doubleValue();
+        }
+        return type.isInstance(value) ? value : null;
+    }
+
+    private static Object normalize(Object value){
+        if (value instanceof Integer || value instanceof Short || value instanceof Byte){
+            return ((Number) value).longValue();
+        }
+        if (value instanceof Float){
+            return ((Float) value).doubleValue();
+        }
+        if (value instanceof List){
+            List<Object> list = new ArrayList<Object>();
+            for (Object item : (List<?>) value){
+                list.add(normalize(item));
+            }
+            return list;
+        }
+        if (value instanceof String || value instanceof Long || value instanceof Double || value instanceof Boolean){
+            return value;
+        }
+        throw new IllegalArgumentException("Unsupported config value : " + value);
+    }
+
+    private static Object parseValue(String text){
+        if (text.isEmpty()){
+            throw new IllegalArgumentException("missing value");
+        }
+        if (text.startsWith("[")){
+            if (!text.endsWith("]")){
+                throw new IllegalArgumentException("unterminated array");
+            }
+            List<Object> list = new ArrayList<Object>();
+            String body = text.substring(1, text.length() - 1).trim();
+            if (!body.isEmpty()){
+                for (String item : splitArray(body)){
+                    Object value = parseValue(item.trim());
+                    if (value instanceof List){
+                        throw new IllegalArgumentException("nested arrays are not supported");
+                    }
+                    list.add(value);
+                }
+            }
+            return list;
+        }
+        if (text.startsWith("\"")){
+            return parseString(text);
+        }
+        if (text.equals("true") || text.equals("false")){
+            return Boolean.valueOf(text);
+        }
+        try {
+            return Long.parseLong(text);
+        } catch (NumberFormatException e){
+            // not an integer, try a float below
+        }
+        try {
+            double d = Double.parseDouble(text);
+            if (!Double.isNaN(d) && !Double.isInfinite(d)){
+                return d;
+            }
+        } catch (NumberFormatException e){
+            // reported below
+        }
+        throw new IllegalArgumentException("cannot parse value '" + text + "'");
+    }
+
+    private static String parseString(String text){
+        StringBuilder out = new StringBuilder();
+        for (int i = 1; i < text.length(); i++){
+            char c = text.charAt(i);
+            if (c == '"'){
+                if (i != text.length() - 1){
+                    throw new IllegalArgumentException("trailing characters after string");
+                }
+                return out.toString();
+            }
+            if (c == '\\' && i + 1 < text.length()){
+                char next = text.charAt(++i);
+                switch (next){
+                    case 'n': out.append('\n'); break;
+                    case 't': out.append('\t'); break;
+                    case '"': out.append('"'); break;
+                    case '\\': out.append('\\'); break;
+                    default: throw new IllegalArgumentException("unknown escape \\" + next);
+                }
+            } else {
+                out.append(c);
+            }
+        }
+        throw new IllegalArgumentException("unterminated string");
+    }
+
+    // Splits on commas outside quoted strings.
+    private static List<String> splitArray(String body){
+        List<String> items = new ArrayList<String>();
+        boolean quoted = false;
+        int start = 0;
+        for (int i = 0; i < body.length(); i++){
+            char c = body.charAt(i);
+            if (c == '\\' && quoted){
+                i++;
+            } else if (c == '"'){
+                quoted = !quoted;
+            } else if (c == ',' && !quoted){
+                items.add(body.substring(start, i));
+                start = i + 1;
+            }
+        }
+        items.add(body.substring(start));
+        return items;
+    }
+
+    private static String stripComment(String line){
+        boolean quoted = false;
+        for (int i = 0; i < line.length(); i++){
+            char c = line.charAt(i);
+            if (c == '\\' && quoted){
+                i++;
+            } else if (c == '"'){
+                quoted = !quoted;
+            } else if (c == '#' && !quoted){
+                return line.substring(0, i);
+            }
+        }
+        return line;
+    }
+
+    private static boolean isPath(String path){
+        if (path.isEmpty()){
+            return false;
+        }
+        for (String part : path.split("\\.", -1)){
+            if (part.isEmpty()){
+                return false;
+            }
+            for (char c : part.toCharArray()){
+                if (!Character.isLetterOrDigit(c) && c != '_' && c != '-'){
+                    return false;
+                }
+            }
+        }
+        return true;
+    }
+
+    private static String format(Object value){
+        if (value instanceof String){
+            String s = (String) value;
+            return "\"" + s.replace("\\", "\\\\").replace("\"", "\\\"").replace("\n", "\\n").replace("\t", "\\t") + "\"";
+        }
+        if (value instanceof List){
+            List<String> items = new ArrayList<String>();
+            for (Object item : (List<?>) value){
+                items.add(format(item));
+            }
+            return "[" + String.join(", ", items) + "]";
+        }
+        return String.valueOf(value);
+    }
+
+    private static String typeName(Class<?> type){
+        if (type == Long.class){
+            return "integer";
+        }
+        if (type == Double.class){
+            return "float";
+        }
+        if (type == Boolean.class){
+            return "bool";
+        }
+        if (type == String.class){
+            return "string";
+        }
+        if (List.class.isAssignableFrom(type)){
+            return "array";
+        }
+        return type.getSimpleName();
+    }
+}
@@ -0,0 +1,16 @@
+package Util.Config;
+
+import java.util.List;
+
+public class ConfigException extends Exception {
+    private static final long serialVersionUID = 1L;
+
+    private final List<String> problems;
+
+    public ConfigException(List<String> problems){
+        super(String.join("\n", problems));
+        this.problems = problems;
+    }
+
+    public List<String> getProblems(){ return problems;}
+}
@@ -0,0 +1,240 @@
+package Util;
+
+// Shared checksums. Each algorithm is a small streaming object
+// (new, update..., finish) with a one-shot compute() helper; the CRCs are
+// table driven and keep a bitwise reference implementation alongside.
+// Check values are for the ASCII string "123456789".
+public final class Crc {
+    private static final int[] CRC8_TABLE = msbTable(0x07, 8);
+    private static final int[] CCITT_TABLE = msbTable(0x1021, 16);
+    private static final int[] CCITT_REFLECTED_TABLE = lsbTable(0x8408);
+    private static final int[] CRC32_TABLE = lsbTable(0xEDB88320);
+
+    private Crc(){}
+
+    // Table for a non-reflected CRC of the given width (8 or 16 bits).
+    public static int[] msbTable(int poly, int width){
+        int top = 1 << (width - 1);
+        int mask = (1 << width) - 1;
+        int[] table = new int[256];
+        for (int i = 0; i < 256; i++){
+            int crc = i << (width - 8);
+            for (int bit = 0; bit < 8; bit++){
+                crc = (crc & top) != 0 ? (crc << 1) ^ poly : crc << 1;
+            }
+            table[i] = crc & mask;
+        }
+        return table;
+    }
+
+    // Table for a reflected CRC, given the bit-reversed polynomial.
+    public static int[] lsbTable(int reversedPoly){
+        int[] table = new int[256];
+        for (int i = 0; i < 256; i++){
+            int crc = i;
+            for (int bit = 0; bit < 8; bit++){
+                crc = (crc & 1) != 0 ? (crc >>> 1) ^ reversedPoly : crc >>> 1;
+            }
+            table[i] = crc;
+        }
+        return table;
+    }
+
+    // CRC-8/SMBUS: poly 0x07, init 0x00. Check 0xF4.
+    public static class Crc8 {
+        private int crc;
+
+        public Crc8 update(byte[] data, int off, int len){
+            for (int i = off; i < off + len; i++){
+                crc = CRC8_TABLE[(crc ^ data[i]) & 0xFF];
+            }
+            return this;
+        }
+
+        public Crc8 update(byte[] data){ return update(data, 0, data.length);}
+
+        public int finish(){ return crc;}
+
+        public void reset(){ crc = 0;}
+
+        public static int compute(byte[] data){ return new Crc8().update(data).finish();}
+
+        public static int computeBitwise(byte[] data){
+            int crc = 0;
+            for (byte b : data){
+                crc ^= b & 0xFF;
+                for (int bit = 0; bit < 8; bit++){
+                    crc = (crc & 0x80) != 0 ? ((crc << 1) ^ 0x07) & 0xFF : (crc << 1) & 0xFF;
+                }
+            }
+            return crc;
+        }
+    }
+
+    // CRC-16/CCITT-FALSE: poly 0x1021, init 0xFFFF. Check 0x29B1.
+    public static class Crc16Ccitt {
+        private int crc = 0xFFFF;
+
+        public Crc16Ccitt update(byte[] data, int off, int len){
+            for (int i = off; i < off + len; i++){
+                crc = ((crc << 8) ^ CCITT_TABLE[((crc >> 8) ^ data[i]) & 0xFF]) & 0xFFFF;
+            }
+            return this;
+        }
+
+        public Crc16Ccitt update(byte[] data){ return update(data, 0, data.length);}
+
+        public int finish(){ return crc;}
+
+        public void reset(){ crc = 0xFFFF;}
+
+        public static int compute(byte[] data){ return new Crc16Ccitt().update(data).finish();}
+
+        public static int computeBitwise(byte[] data){
+            int crc = 0xFFFF;
+            for (byte b : data){
+                crc ^= (b & 0xFF) << 8;
+                for (int bit = 0; bit < 8; bit++){
+                    crc = (crc & 0x8000) != 0 ? ((crc << 1) ^ 0x1021) & 0xFFFF : (crc << 1) & 0xFFFF;
+                }
+            }
+            return crc;
+        }
+    }
+
+    // CRC-16/MCRF4XX, the checksum MAVLink calls X.25: reflected poly 0x1021,
+    // init 0xFFFF, no final xor. Check 0x6F91.
+    public static class Crc16Mcrf4xx {
+        private int crc = 0xFFFF;
+
+        public Crc16Mcrf4xx update(byte[] data, int off, int len){
+            for (int i = off; i < off + len; i++){
+                crc = (crc >>> 8) ^ CCITT_REFLECTED_TABLE[(crc ^ data[i]) & 0xFF];
+            }
+            return this;
+        }
+
+        public Crc16Mcrf4xx update(byte[] data){ return update(data, 0, data.length);}
+
+        public Crc16Mcrf4xx update(int b){
+            crc = (crc >>> 8) ^ CCITT_REFLECTED_TABLE[(crc ^ b) & 0xFF];
+            return this;
+        }
+
+        public int finish(){ return crc;}
+
+        public void reset(){ crc = 0xFFFF;}
+
+        public static int compute(byte[] data){ return new Crc16Mcrf4xx().update(data).finish();}
+
+        public static int computeBitwise(byte[] data){
+            int crc = 0xFFFF;
+            for (byte b : data){
+                crc ^= b & 0xFF;
+                for (int bit = 0; bit < 8; bit++){
+                    crc = (crc & 1) != 0 ? (crc >>> 1) ^ 0x8408 : crc >>> 1;
+                }
+            }
+            return crc;
+        }
+    }
+
+    // CRC-16/X-25 as used by HDLC and PPP: the MCRF4XX register with a final
+    // xor of 0xFFFF. Check 0x906E.
+    public static class Crc16X25 {
+        private final Crc16Mcrf4xx inner = new Crc16Mcrf4xx();
+
+        public Crc16X25 update(byte[] data, int off, int len){
+            inner.update(data, off, len);
+            return this;
+        }
+
+        public Crc16X25 update(byte[] data){ return update(data, 0, data.length);}
+
+        public Crc16X25 update(int b){
+            inner.update(b);
+            return this;
+        }
+
+        public int finish(){ return inner.finish() ^ 0xFFFF;}
+
+        public void reset(){ inner.reset();}
+
+        public static int compute(byte[] data){ return new Crc16X25().update(data).finish();}
+
+        public static int computeBitwise(byte[] data){ return Crc16Mcrf4xx.computeBitwise(data) ^ 0xFFFF;}
+    }
+
+    // CRC-32/IEEE 802.3 as used by zip and Ethernet. Check 0xCBF43926.
+    public static class Crc32 {
+        private int crc = 0xFFFFFFFF;
+
+        public Crc32 update(byte[] data, int off, int len){
+            for (int i = off; i < off + len; i++){
+                crc = (crc >>> 8) ^ CRC32_TABLE[(crc ^ data[i]) & 0xFF];
+            }
+            return this;
+        }
+
+        public Crc32 update(byte[] data){ return update(data, 0, data.length);}
+
+        public long finish(){ return ~crc & 0xFFFFFFFFL;}
+
+        public void reset(){ crc = 0xFFFFFFFF;}
+
+        public static long compute(byte[] data){ return new Crc32().update(data).finish();}
+
+        public static long computeBitwise(byte[] data){
+            int crc = 0xFFFFFFFF;
+            for (byte b : data){
+                crc ^= b & 0xFF;
+                for (int bit = 0; bit < 8; bit++){
+                    crc = (crc & 1) != 0 ? (crc >>> 1) ^ 0xEDB88320 : crc >>> 1;
+                }
+            }
+            return ~crc & 0xFFFFFFFFL;
+        }
+    }
+
+    // NMEA 0183 sentence checksum: XOR of every byte between '$' and '*'. Check 0x31.
+    public static class NmeaXor {
+        private int sum;
+
+        public NmeaXor update(byte[] data, int off, int len){
+            for (int i = off; i < off + len; i++){
+                sum ^= data[i] & 0xFF;
+            }
+            return this;
+        }
+
+        public NmeaXor update(byte[] data){ return update(data, 0, data.length);}
+
+        public int finish(){ return sum;}
+
+        public void reset(){ sum = 0;}
+
+        public static int compute(byte[] data){ return new NmeaXor().update(data).finish();}
+    }
+
+    // Fletcher-16 with modulo 255 sums. Check 0x1EDE.
+    public static class Fletcher16 {
+        private int sum1;
+        private int sum2;
+
+        public Fletcher16 update(byte[] data, int off, int len){
+            for (int i = off; i < off + len; i++){
+                sum1 = (sum1 + (data[i] & 0xFF)) % 255;
+                sum2 = (sum2 + sum1) % 255;
+            }
+            return this;
+        }
+
+        public Fletcher16 update(byte[] data){ return update(data, 0, data.length);}
+
+        public int finish(){ return sum2 << 8 | sum1;}
+
+        public void reset(){ sum1 = 0; sum2 = 0;}
+
+        public static int compute(byte[] data){ return new Fletcher16().update(data).finish();}
+    }
+}
@@ -0,0 +1,131 @@
+package Util;
+
+import Util.Enums.ObligationState;
+
+import java.util.ArrayList;
+import java.util.LinkedHashMap;
+import java.util.List;
+import java.util.Map;
+
+// Named obligations that must be kicked within their timeout. tick() runs the
+// action of each overdue obligation once; it will not fire again until the
+// obligation has been kicked and has expired anew. Paused obligations never
+// fire and get a fresh deadline when resumed. Actions run after the internal
+// lock is released, so they may call back into the supervisor. An action
+// that throws a RuntimeException is counted and does not stop the remaining
+// actions; Errors propagate out of tick.
+public class DeadlineSupervisor {
+    private final Map<String, Obligation> obligations = new LinkedHashMap<String, Obligation>();
+    private long actionFailures;
+
+    private static class Obligation {
+        final long timeoutMs;
+        final Runnable action;
+        long deadlineMs;
+        ObligationState state = ObligationState.OK;
+        long expiries;
+
+        Obligation(long timeoutMs, Runnable action, long nowMs){
+            this.timeoutMs = timeoutMs;
+            this.action = action;
+            this.deadlineMs = nowMs + timeoutMs;
+        }
+    }
+
+    public static class Status {
+        private final ObligationState state;
+        private final long deadlineMs;
+        private final long expiries;
+
+        Status(ObligationState state, long deadlineMs, long expiries){
+            this.state = state;
+            this.deadlineMs = deadlineMs;
+            this.expiries = expiries;
+        }
+
+        public ObligationState getState(){ return state;}
+
+        public long getDeadlineMs(){ return deadlineMs;}
+
+        public long getExpiries(){ return expiries;}
+    }
+
+    public synchronized void register(String name, long timeoutMs, Runnable action, long nowMs){
+        if (obligations.containsKey(name)){
+            throw new IllegalArgumentException("Obligation already registered : " + name);
+        }
+        obligations.put(name, new Obligation(timeoutMs, action, nowMs));
+    }
+
+    public synchronized boolean unregister(String name){
+        return obligations.remove(name) != null;
+    }
+
+    public synchronized void kick(String name, long nowMs){
+        Obligation o = get(name);
+        o.deadlineMs = nowMs + o.timeoutMs;
+        if (o.state == ObligationState.EXPIRED){
+            o.state = ObligationState.OK;
+        }
+    }
+
+    public synchronized void pause(String name){
+        get(name).state = ObligationState.PAUSED;
+    }
+
+    public synchronized void resume(String name, long nowMs){
+        Obligation o = get(name);
+        if (o.state == ObligationState.PAUSED){
+            o.state = ObligationState.OK;
+            o.deadlineMs = nowMs + o.timeoutMs;
+        }
+    }
+
+    // Returns the names whose actions ran.
+    public List<String> tick(long nowMs){
+        List<String> fired = new ArrayList<String>();
+        List<Runnable> actions = new ArrayList<Runnable>();
+        synchronized (this){
+            for (Map.Entry<String, Obligation> entry : obligations.entrySet()){
+                Obligation o = entry.getValue();
+                if (o.state == ObligationState.OK && nowMs >= o.deadlineMs){
+                    o.state = ObligationState.EXPIRED;
+                    o.expiries++;
+                    fired.add(entry.getKey());
+                    actions.add(o.action);
+                }
+            }
+        }
+        for (Runnable action : actions){
+            try {
+                action.run();
+            } catch (RuntimeException e){
+                synchronized (this){
+                    actionFailures++;
+                }
+            }
+        }
+        return fired;
+    }
+
+    public synchronized long getActionFailures(){
+        return actionFailures;
+    }
+
+    public synchronized Map<String, Status> status(){
+        Map<String, Status> out = new LinkedHashMap<String, Status>();
+        for (Map.Entry<String, Obligation> entry : obligations.entrySet()){
+            Obligation o = entry.getValue();
+            out.put(entry.getKey(), new Status(o.state, o.deadlineMs, o.expiries));
+        }
+        return out;
+    }
+
+    private Obligation get(String name){
+        Obligation o = obligations.get(name);
+        if (o == null){
+            throw new IllegalArgumentException("Unknown obligation : " + name);
+        }
+        return o;
+    }
+}
@@ -0,0 +1,5 @@
+package Util.Enums;
+
+public enum DroneCommand {
+     ARM, DISARM, TAKEOFF, LAND, HOVER, RETURN_TO_LAUNCH;
+}
@@ -0,0 +1,5 @@
+package Util.Enums;
+
+public enum LinkGrade {
+     GOOD, DEGRADED, POOR, LOST;
+}
@@ -0,0 +1,5 @@
+package Util.Enums;
+
+public enum LogLevel {
+     TRACE, DEBUG, INFO, WARN, ERROR;
+}
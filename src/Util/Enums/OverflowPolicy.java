@@ -0,0 +1,5 @@
+package Util.Enums;
+
+public enum OverflowPolicy {
+     REJECT, OVERWRITE;
+}
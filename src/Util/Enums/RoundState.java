@@ -0,0 +1,5 @@
+package Util.Enums;
+
+public enum RoundState {
+     IDLE, PENDING, COMPLETE, TIMED_OUT;
+}
@@ -0,0 +1,5 @@
+package Util.Enums;
+
+public enum TransitionResult {
+     ACCEPTED, REJECTED_BY_GUARD, NO_TRANSITION;
+}
@@ -0,0 +1,108 @@
+package Util;
+
+import java.util.ArrayDeque;
+import java.util.ArrayList;
+import java.util.Deque;
+import java.util.HashMap;
+import java.util.List;
+import java.util.Map;
+import java.util.function.Consumer;
+
+// In-process publish/subscribe keyed by the event's exact class. Listeners
+// run synchronously in subscription order. An event published from inside a
+// listener is queued and delivered once the current dispatch has finished.
+// A listener unsubscribed during a dispatch is not called for the rest of it;
+// one subscribed during a dispatch first sees the next event. A
+// RuntimeException thrown by a listener is caught and counted so the others
+// still run. Errors propagate out of publish, dropping any queued events, and
+// the bus is left ready for the next publish.
+public class EventBus {
+    private final Map<Class<?>, List<Subscription<?>>> listeners = new HashMap<Class<?>, List<Subscription<?>>>();
+    private final Deque<Object> pending = new ArrayDeque<Object>();
+    private boolean dispatching;
+    private long delivered;
+    private long failures;
+
+    public class Subscription<T> implements AutoCloseable {
+        private final Class<T> type;
+        private final Consumer<? super T> listener;
+        private boolean active = true;
+
+        Subscription(Class<T> type, Consumer<? super T> listener){
+            this.type = type;
+            this.listener = listener;
+        }
+
+        public boolean isActive(){ return active;}
+
+        // Unsubscribes; safe to call more than once.
+        @Override
+        public void close(){
+            if (!active){
+                return;
+            }
+            active = false;
+            List<Subscription<?>> list = listeners.get(type);
+            if (list != null){
+                list.remove(this);
+            }
+        }
+    }
+
+    public <T> Subscription<T> subscribe(Class<T> type, Consumer<? super T> listener){
+        Subscription<T> subscription = new Subscription<T>(type, listener);
+        List<Subscription<?>> list = listeners.get(type);
+        if (list == null){
+            list = new ArrayList<Subscription<?>>();
+            listeners.put(type, list);
+        }
+        list.add(subscription);
+        return subscription;
+    }
+
+    public void publish(Object event){
+        pending.addLast(event);
+        if (dispatching){
+            return;
+        }
+        dispatching = true;
+        try {
+            while (!pending.isEmpty()){
+                dispatch(pending.pollFirst());
+            }
+        } finally {
+            // Only reached with events left if dispatch itself failed; they
+            // are dropped so the next publish starts clean.
+            pending.clear();
+            dispatching = false;
+        }
+    }
+
+    public int listenerCount(Class<?> type){
+        List<Subscription<?>> list = listeners.get(type);
+        return list == null ? 0 : list.size();
+    }
+
+    public long getDelivered(){ return delivered;}
+
+    public long getFailures(){ return failures;}
+
+    @SuppressWarnings("unchecked")
+    private void dispatch(Object event){
+        List<Subscription<?>> list = listeners.get(event.getClass());
+        if (list == null){
+            return;
+        }
+        for (Subscription<?> subscription : new ArrayList<Subscription<?>>(list)){
+            if (!subscription.active){
+                continue;
+            }
+            try {
+                ((Consumer<Object>) subscription.listener).accept(event);
+                delivered++;
+            } catch (RuntimeException e){
+                failures++;
+            }
+        }
+    }
+}
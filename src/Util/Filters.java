@@ -0,0 +1,241 @@
+package Util;
+
+import Util.Enums.Edge;
+
+import java.util.Arrays;
+
+// Small scalar filters sharing the update(x) / reset() shape.
+// NaN policy: a NaN input is rejected. It leaves the filter state untouched,
+// update returns the previous output (NaN if there is none yet) and the
+// sample is counted in getRejected(). Infinite inputs are treated the same.
+public final class Filters {
+    private Filters(){}
+
+    private static boolean invalid(double x){
+        return Double.isNaN(x) || Double.isInfinite(x);
+    }
+
+    // Mean of the last n samples, O(1) per update with a running sum.
+    public static class MovingAverage {
+        private final double[] window;
+        private int next;
+        private int count;
+        private double sum;
+        private double output = Double.NaN;
+        private long rejected;
+
+        public MovingAverage(int n){
+            if (n <= 0){
+                throw new IllegalArgumentException("Window must be positive : " + n);
+            }
+            window = new double[n];
+        }
+
+        public double update(double x){
+            if (invalid(x)){
+                rejected++;
+                return output;
+            }
+            if (count == window.length){
+                sum -= window[next];
+            } else {
+                count++;
+            }
+            window[next] = x;
+            sum += x;
+            next = (next + 1) % window.length;
+            output = sum / count;
+            return output;
+        }
+
+        public void reset(){
+            Arrays.fill(window, 0);
+            next = 0;
+            count = 0;
+            sum = 0;
+            output = Double.NaN;
+        }
+
+        public double getOutput(){ return output;}
+
+        public long getRejected(){ return rejected;}
+    }
+
+    // Single-pole IIR: y += alpha * (x - y), alpha = dt / (RC + dt) with
+    // RC = 1 / (2 pi cutoff). The first sample initialises the output.
+    public static class LowPass {
+        private final double alpha;
+        private double output = Double.NaN;
+        private long rejected;
+
+        public LowPass(double cutoffHz, double dtSec){
+            if (cutoffHz <= 0 || dtSec <= 0){
+                throw new IllegalArgumentException("Cutoff and dt must be positive");
+            }
+            double rc = 1 / (2 * Math.PI * cutoffHz);
+            this.alpha = dtSec / (rc + dtSec);
+        }
+
+        public double update(double x){
+            if (invalid(x)){
+                rejected++;
+                return output;
+            }
+            output = Double.isNaN(output) ? x : output + alpha * (x - output);
+            return output;
+        }
+
+        public void reset(){
+            output = Double.NaN;
+        }
+
+        public double getAlpha(){ return alpha;}
+
+        public double getOutput(){ return output;}
+
+        public long getRejected(){ return rejected;}
+    }
+
+    // Median of the last n samples (n odd). A sorted copy of the window is
+    // kept alongside it, so an update is one removal and one insertion. Until
+    // the window fills the median is taken over the samples seen so far.
+    public static class MedianFilter {
+        private final double[] window;
+        private final double[] sorted;
+        private int next;
+        private int count;
+        private double output = Double.NaN;
+        private long rejected;
+
+        public MedianFilter(int n){
+            if (n <= 0 || n % 2 == 0){
+                throw new IllegalArgumentException("Window must be odd and positive : " + n);
+            }
+            window = new double[n];
+            sorted = new double[n];
+        }
+
+        public double update(double x){
+            if (invalid(x)){
+                rejected++;
+                return output;
+            }
+            if (count == window.length){
+                int old = Arrays.binarySearch(sorted, 0, count, window[next]);
+                System.arraycopy(sorted, old + 1, sorted, old, count - old - 1);
+                count--;
+            }
+            int pos = Arrays.binarySearch(sorted, 0, count, x);
+            if (pos < 0){
+                pos = -pos - 1;
+            }
+            System.arraycopy(sorted, pos, sorted, pos + 1, count - pos);
+            sorted[pos] = x;
+            count++;
+            window[next] = x;
+            next = (next + 1) % window.length;
+            int mid = count / 2;
+            output = count % 2 == 1 ? sorted[mid] : (sorted[mid - 1] + sorted[mid]) / 2;
+            return output;
+        }
+
+        public void reset(){
+            next = 0;
+            count = 0;
+            output = Double.NaN;
+        }
+
+        public double getOutput(){ return output;}
+
+        public long getRejected(){ return rejected;}
+    }
+
+    // Derivative per second of a signal sampled every dtSec, smoothed with an
+    // exponential average (alpha = 1 means no smoothing). Returns 0 until two
+    // samples have been seen.
+    public static class RateOfChange {
+        private final double dtSec;
+        private final double alpha;
+        private double previous = Double.NaN;
+        private double output;
+        private long rejected;
+
+        public RateOfChange(double dtSec, double alpha){
+            if (dtSec <= 0 || alpha <= 0 || alpha > 1){
+                throw new IllegalArgumentException("dt must be positive and alpha within (0, 1]");
+            }
+            this.dtSec = dtSec;
+            this.alpha = alpha;
+        }
+
+        public double update(double x){
+            if (invalid(x)){
+                rejected++;
+                return output;
+            }
+            if (!Double.isNaN(previous)){
+                double rate = (x - previous) / dtSec;
+                output += alpha * (rate - output);
+            }
+            previous = x;
+            return output;
+        }
+
+        public void reset(){
+            previous = Double.NaN;
+            output = 0;
+        }
+
+        public double getOutput(){ return output;}
+
+        public long getRejected(){ return rejected;}
+    }
+
+    // Two-level threshold: goes high at or above riseLevel, low at or below
+    // fallLevel, and reports the edge when it switches.
+    public static class Hysteresis {
+        private final double riseLevel;
+        private final double fallLevel;
+        private final boolean initial;
+        private boolean high;
+        private long rejected;
+
+        public Hysteresis(double riseLevel, double fallLevel, boolean initial){
+            if (fallLevel > riseLevel){
+                throw new IllegalArgumentException("fallLevel must not exceed riseLevel");
+            }
+            this.riseLevel = riseLevel;
+            this.fallLevel = fallLevel;
+            this.initial = initial;
+            this.high = initial;
+        }
+
+        public Hysteresis(double riseLevel, double fallLevel){
+            this(riseLevel, fallLevel, false);
+        }
+
+        public Edge update(double x){
+            if (invalid(x)){
+                rejected++;
+                return Edge.NONE;
+            }
+            if (!high && x >= riseLevel){
+                high = true;
+                return Edge.RISING;
+            }
+            if (high && x <= fallLevel){
+                high = false;
+                return Edge.FALLING;
+            }
+            return Edge.NONE;
+        }
+
+        public void reset(){
+            high = initial;
+        }
+
+        public boolean isHigh(){ return high;}
+
+        public long getRejected(){ return rejected;}
+    }
+}
@@ -0,0 +1,298 @@
+package Util;
+
+// Signed fixed-point number: a 32-bit raw value with fracBits fractional bits
+// (fracBits = 16 gives Q16.16). Both operands of an operation must share the
+// same format. The plain arithmetic methods throw ArithmeticException on
+// overflow and the saturating variants clamp; nothing ever wraps silently.
+// Products and quotients are rounded to nearest, ties away from zero.
+// Everything except the fromFloat/toDouble conversions is integer only.
+public final class Fixed implements Comparable<Fixed> {
+    // Q16.16, the format for controllers on targets without an FPU.
+    public static final int Q16 = 16;
+
+    // Angle constants in Q30 and Q60; trig runs entirely on these integers.
+    public static final int HALF_PI_Q30 = 1686629713;
+    private static final long TWO_PI_Q60 = 7244019458077122842L;
+    private static final long TWO_PI_Q30 = TWO_PI_Q60 >> 30;
+    private static final long TWO_PI_Q60_LOW = TWO_PI_Q60 & ((1L << 30) - 1);
+
+    // CORDIC: atan(2^-i) in Q30 and the gain 1/prod(sqrt(1 + 2^-2i)) in Q30.
+    private static final int[] ATAN_Q30 = {
+        843314857, 497837829, 263043837, 133525159, 67021687, 33543516, 16775851, 8388437,
+        4194283, 2097149, 1048576, 524288, 262144, 131072, 65536, 32768,
+        16384, 8192, 4096, 2048, 1024, 512, 256, 128, 64, 32, 16, 8, 4, 2, 1
+    };
+    private static final long CORDIC_GAIN_Q30 = 652032874;
+
+    private final int raw;
+    private final int fracBits;
+
+    private Fixed(int raw, int fracBits){
+        this.raw = raw;
+        this.fracBits = fracBits;
+    }
+
+    public static Fixed fromRaw(int raw, int fracBits){
+        if (fracBits < 0 || fracBits > 30){
+            throw new IllegalArgumentException("fracBits must be within 0..30 : " + fracBits);
+        }
+        return new Fixed(raw, fracBits);
+    }
+
+    public static Fixed zero(int fracBits){
+        return fromRaw(0, fracBits);
+    }
+
+    public static Fixed one(int fracBits){
+        return fromRaw(1 << fracBits, fracBits);
+    }
+
+    public static Fixed fromInt(int value, int fracBits){
+        return fromRaw(toRaw((long) value << fracBits), fracBits);
+    }
+
+    public static Fixed fromFloat(double value, int fracBits){
+        if (Double.isNaN(value) || Double.isInfinite(value)){
+            throw new ArithmeticException("Cannot represent " + value);
+        }
+        return fromRaw(toRaw(Math.round(value * (1L << fracBits))), fracBits);
+    }
+
+    public static Fixed q16(double value){
+        return fromFloat(value, Q16);
+    }
+
+    public static Fixed saturatingFromFloat(double value, int fracBits){
+        if (Double.isNaN(value)){
+            throw new ArithmeticException("Cannot represent NaN");
+        }
+        double scaled = Math.rint(value * (1L << fracBits));
+        return fromRaw((int) Math.max(Integer.MIN_VALUE, Math.min(Integer.MAX_VALUE, scaled)), fracBits);
+    }
+
+    public int getRaw(){ return raw;}
+
+    public int getFracBits(){ return fracBits;}
+
+    public double toDouble(){
+        return (double) raw / (1L << fracBits);
+    }
+
+    public float toFloat(){
+        return (float) toDouble();
+    }
+
+    // Truncates toward zero.
+    public int toInt(){
+        return (int) (raw >= 0 ? raw >> fracBits : -((-(long) raw) >> fracBits));
+    }
+
+    public Fixed add(Fixed o){
+        return with(toRaw((long) raw + same(o).raw));
+    }
+
+    public Fixed sub(Fixed o){
+        return with(toRaw((long) raw - same(o).raw));
+    }
+
+    public Fixed mul(Fixed o){
+        return with(toRaw(roundShift((long) raw * same(o).raw, fracBits)));
+    }
+
+    public Fixed div(Fixed o){
+        return with(toRaw(quotient(o)));
+    }
+
+    public Fixed saturatingAdd(Fixed o){
+        return with(saturate((long) raw + same(o).raw));
+    }
+
+    public Fixed saturatingSub(Fixed o){
+        return with(saturate((long) raw - same(o).raw));
+    }
+
+    public Fixed saturatingMul(Fixed o){
+        return with(saturate(roundShift((long) raw * same(o).raw, fracBits)));
+    }
+
+    public Fixed saturatingDiv(Fixed o){
+        return with(saturate(quotient(o)));
+    }
+
+    // Variants that return null instead of throwing on overflow.
+    public Fixed checkedAdd(Fixed o){
+        long r = (long) raw + same(o).raw;
+        return fits(r) ? with((int) r) : null;
+    }
+
+    public Fixed checkedMul(Fixed o){
+        long r = roundShift((long) raw * same(o).raw, fracBits);
+        return fits(r) ? with((int) r) : null;
+    }
+
+    public Fixed checkedSub(Fixed o){
+        long r = (long) raw - same(o).raw;
+        return fits(r) ? with((int) r) : null;
+    }
+
+    // Also null for a zero divisor.
+    public Fixed checkedDiv(Fixed o){
+        if (same(o).raw == 0){
+            return null;
+        }
+        long r = quotient(o);
+        return fits(r) ? with((int) r) : null;
+    }
+
+    public Fixed neg(){
+        return with(toRaw(-(long) raw));
+    }
+
+    public Fixed abs(){
+        return raw < 0 ? neg() : this;
+    }
+
+    // Integer Newton iteration on raw << fracBits; the result is the exact
+    // floor of the true root, so the error is below one unit in the last place.
+    public Fixed sqrt(){
+        if (raw < 0){
+            throw new ArithmeticException("sqrt of negative value " + this);
+        }
+        long n = (long) raw << fracBits;
+        if (n == 0){
+            return with(0);
+        }
+        long x = n;
+        long y = (x + 1) / 2;
+        while (y < x){
+            x = y;
+            y = (x + n / x) / 2;
+        }
+        return with(toRaw(x));
+    }
+
+    // Angle in radians. Integer only: the angle is reduced modulo 2 pi in Q30
+    // (with a Q60 constant, so the reduction stays exact to well below one unit
+    // in the last place), folded into a quadrant and run through 30 CORDIC
+    // iterations. The result is within one unit in the last place of the
+    // format, plus 1e-8.
+    public Fixed sin(){
+        long[] cs = cosSin();
+        return with(toRaw(roundShift(cs[1], 30 - fracBits)));
+    }
+
+    public Fixed cos(){
+        long[] cs = cosSin();
+        return with(toRaw(roundShift(cs[0], 30 - fracBits)));
+    }
+
+    public static Fixed halfPi(int fracBits){
+        return fromRaw((int) roundShift(HALF_PI_Q30, 30 - fracBits), fracBits);
+    }
+
+    // {cos, sin} of this angle in Q30.
+    private long[] cosSin(){
+        long angle = (long) raw << (30 - fracBits);
+        long turns = angle / TWO_PI_Q30;
+        long r = angle - turns * TWO_PI_Q30 - ((turns * TWO_PI_Q60_LOW) >> 30);
+        while (r < 0){
+            r += TWO_PI_Q30;
+        }
+        while (r >= TWO_PI_Q30){
+            r -= TWO_PI_Q30;
+        }
+        int quadrant = (int) Math.min(3, r / HALF_PI_Q30);
+        long z = r - quadrant * (long) HALF_PI_Q30;
+        long x = CORDIC_GAIN_Q30;
+        long y = 0;
+        for (int i = 0; i < ATAN_Q30.length; i++){
+            long dx = y >> i;
+            long dy = x >> i;
+            if (z >= 0){
+                x -= dx;
+                y += dy;
+                z -= ATAN_Q30[i];
+            } else {
+                x += dx;
+                y -= dy;
+                z += ATAN_Q30[i];
+            }
+        }
+        switch (quadrant){
+            case 0: return new long[]{x, y};
+            case 1: return new long[]{-y, x};
+            case 2: return new long[]{-x, -y};
+            default: return new long[]{y, -x};
+        }
+    }
+
+    public int compareTo(Fixed o){
+        return Integer.compare(raw, same(o).raw);
+    }
+
+    @Override
+    public boolean equals(Object o){
+        if (!(o instanceof Fixed)){
+            return false;
+        }
+        Fixed f = (Fixed) o;
+        return raw == f.raw && fracBits == f.fracBits;
+    }
+
+    @Override
+    public int hashCode(){
+        return 31 * raw + fracBits;
+    }
+
+    @Override
+    public String toString(){
+        return Double.toString(toDouble());
+    }
+
+    private Fixed with(int newRaw){
+        return new Fixed(newRaw, fracBits);
+    }
+
+    private Fixed same(Fixed o){
+        if (o.fracBits != fracBits){
+            throw new IllegalArgumentException("Mixed fixed-point formats : " + fracBits + " and " + o.fracBits);
+        }
+        return o;
+    }
+
+    private long quotient(Fixed o){
+        if (same(o).raw == 0){
+            throw new ArithmeticException("Division by zero");
+        }
+        long num = (long) raw << fracBits;
+        long q = num / o.raw;
+        long rem = num % o.raw;
+        if (Math.abs(rem) * 2 >= Math.abs((long) o.raw)){
+            q += (num < 0) == (o.raw < 0) ? 1 : -1;
+        }
+        return q;
+    }
+
+    private static long roundShift(long value, int bits){
+        if (bits == 0){
+            return value;
+        }
+        long half = 1L << (bits - 1);
+        return value >= 0 ? (value + half) >> bits : -((-value + half) >> bits);
+    }
+
+    private static boolean fits(long value){
+        return value >= Integer.MIN_VALUE && value <= Integer.MAX_VALUE;
+    }
+
+    private static int toRaw(long value){
+        if (!fits(value)){
+            throw new ArithmeticException("Fixed-point overflow");
+        }
+        return (int) value;
+    }
+
+    private static int saturate(long value){
+        return (int) Math.max(Integer.MIN_VALUE, Math.min(Integer.MAX_VALUE, value));
+    }
+}
@@ -0,0 +1,6 @@
+package Util;
+
+// Implemented by enums used with FlagSet. Each constant owns one distinct bit.
+public interface Flag {
+    int bit();
+}
@@ -0,0 +1,151 @@
+package Util;
+
+import java.util.ArrayList;
+import java.util.Collections;
+import java.util.Comparator;
+import java.util.Iterator;
+import java.util.List;
+
+// Typed bit flags over an enum implementing Flag. The set algebra methods
+// return new sets; insert and remove change this one. Iteration, toString
+// and names() go in ascending bit order.
+public final class FlagSet<F extends Enum<F> & Flag> implements Iterable<F> {
+    private final Class<F> type;
+    private int bits;
+
+    private FlagSet(Class<F> type, int bits){
+        this.type = type;
+        this.bits = bits;
+    }
+
+    public static <F extends Enum<F> & Flag> FlagSet<F> empty(Class<F> type){
+        return new FlagSet<F>(type, 0);
+    }
+
+    public static <F extends Enum<F> & Flag> FlagSet<F> all(Class<F> type){
+        return new FlagSet<F>(type, mask(type));
+    }
+
+    @SafeVarargs
+    public static <F extends Enum<F> & Flag> FlagSet<F> of(Class<F> type, F... flags){
+        FlagSet<F> set = empty(type);
+        for (F flag : flags){
+            set.insert(flag);
+        }
+        return set;
+    }
+
+    // Rejects bits that no flag of the type owns.
+    public static <F extends Enum<F> & Flag> FlagSet<F> fromBits(Class<F> type, int bits){
+        int unknown = bits & ~mask(type);
+        if (unknown != 0){
+            throw new IllegalArgumentException("Unknown " + type.getSimpleName() + " bits : 0x" + Integer.toHexString(unknown));
+        }
+        return new FlagSet<F>(type, bits);
+    }
+
+    public static <F extends Enum<F> & Flag> FlagSet<F> fromBitsTruncate(Class<F> type, int bits){
+        return new FlagSet<F>(type, bits & mask(type));
+    }
+
+    public static <F extends Enum<F> & Flag> FlagSet<F> fromNames(Class<F> type, List<String> names){
+        FlagSet<F> set = empty(type);
+        for (String name : names){
+            set.insert(Enum.valueOf(type, name));
+        }
+        return set;
+    }
+
+    public int getBits(){ return bits;}
+
+    public boolean insert(F flag){
+        boolean added = !contains(flag);
+        bits |= flag.bit();
+        return added;
+    }
+
+    public boolean remove(F flag){
+        boolean removed = contains(flag);
+        bits &= ~flag.bit();
+        return removed;
+    }
+
+    public boolean contains(F flag){
+        return (bits & flag.bit()) != 0;
+    }
+
+    public boolean containsAll(FlagSet<F> o){
+        return (bits & o.bits) == o.bits;
+    }
+
+    public boolean isEmpty(){
+        return bits == 0;
+    }
+
+    public int size(){
+        return Integer.bitCount(bits);
+    }
+
+    public FlagSet<F> union(FlagSet<F> o){
+        return new FlagSet<F>(type, bits | o.bits);
+    }
+
+    public FlagSet<F> intersection(FlagSet<F> o){
+        return new FlagSet<F>(type, bits & o.bits);
+    }
+
+    public FlagSet<F> difference(FlagSet<F> o){
+        return new FlagSet<F>(type, bits & ~o.bits);
+    }
+
+    public FlagSet<F> copy(){
+        return new FlagSet<F>(type, bits);
+    }
+
+    public List<String> names(){
+        List<String> names = new ArrayList<String>();
+        for (F flag : this){
+            names.add(flag.name());
+        }
+        return names;
+    }
+
+    public Iterator<F> iterator(){
+        List<F> set = new ArrayList<F>();
+        for (F flag : type.getEnumConstants()){
+            if (contains(flag)){
+                set.add(flag);
+            }
+        }
+        Collections.sort(set, new Comparator<F>() {
+            public int compare(F a, F b){ return Integer.compareUnsigned(a.bit(), b.bit());}
+        });
+        return set.iterator();
+    }
+
+    @Override
+    public boolean equals(Object o){
+        return o instanceof FlagSet && ((FlagSet<?>) o).type == type && ((FlagSet<?>) o).bits == bits;
+    }
+
+    @Override
+    public int hashCode(){
+        return 31 * type.hashCode() + bits;
+    }
+
+    @Override
+    public String toString(){
+        return "{" + String.join(" | ", names()) + "}";
+    }
+
+    private static <F extends Enum<F> & Flag> int mask(Class<F> type){
+        int mask = 0;
+        for (F flag : type.getEnumConstants()){
+            if (Integer.bitCount(flag.bit()) != 1 || (mask & flag.bit()) != 0){
+                throw new IllegalStateException(type.getSimpleName() + "." + flag.name() + " must own a single distinct bit");
+            }
+            mask |= flag.bit();
+        }
+        return mask;
+    }
+}
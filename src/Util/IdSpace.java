@@ -0,0 +1,70 @@
+package Util;
+
+import java.util.concurrent.atomic.AtomicLong;
+
+// Swarm-unique identifiers: the node prefix (assigned during onboarding) sits
+// in the upper bits and a local counter in the lower localBits bits. With a
+// zero prefix the ids are just 1, 2, 3, ... Ids are unsigned 64-bit values
+// stored in a long. Local id 0 is never handed out.
+public class IdSpace {
+    public static final int DEFAULT_LOCAL_BITS = 48;
+
+    private final long prefix;
+    private final int localBits;
+    private final long localMask;
+    private final AtomicLong counter = new AtomicLong();
+
+    public IdSpace(long prefix, int localBits){
+        if (localBits < 1 || localBits > 63){
+            throw new IllegalArgumentException("localBits must be within 1..63 : " + localBits);
+        }
+        if (prefix < 0 || (prefix >>> (64 - localBits)) != 0){
+            throw new IllegalArgumentException("Prefix " + prefix + " does not fit in " + (64 - localBits) + " bits");
+        }
+        this.prefix = prefix;
+        this.localBits = localBits;
+        this.localMask = (1L << localBits) - 1;
+    }
+
+    public IdSpace(long prefix){
+        this(prefix, DEFAULT_LOCAL_BITS);
+    }
+
+    public IdSpace(){
+        this(0);
+    }
+
+    // Throws IllegalStateException once every local id has been used.
+    public long next(){
+        long local = counter.incrementAndGet();
+        if (local > localMask){
+            counter.set(localMask + 1);
+            throw new IllegalStateException("Id space for node " + prefix + " is exhausted");
+        }
+        return prefix << localBits | local;
+    }
+
+    public boolean isExhausted(){
+        return counter.get() >= localMask;
+    }
+
+    public long remaining(){
+        return Math.max(0, localMask - counter.get());
+    }
+
+    public long getPrefix(){ return prefix;}
+
+    public int getLocalBits(){ return localBits;}
+
+    public long nodeOf(long id){
+        return id >>> localBits;
+    }
+
+    public long localOf(long id){
+        return id & localMask;
+    }
+
+    public boolean owns(long id){
+        return nodeOf(id) == prefix && localOf(id) != 0;
+    }
+}
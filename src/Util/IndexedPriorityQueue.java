@@ -0,0 +1,157 @@
+package Util;
+
+import java.util.ArrayList;
+import java.util.HashMap;
+import java.util.List;
+import java.util.Map;
+
+// Binary min-heap with a key -> slot index, so priorities can be changed and
+// keys removed in O(log n). Equal priorities come out in insertion order.
+// Pushing a key that is already queued replaces its priority and counts as a
+// fresh insertion; updatePriority keeps the original insertion order.
+public class IndexedPriorityQueue<K, P extends Comparable<? super P>> {
+    private final List<Node<K, P>> heap = new ArrayList<Node<K, P>>();
+    private final Map<K, Integer> slots = new HashMap<K, Integer>();
+    private long nextSeq;
+
+    public static class Node<K, P> {
+        private final K key;
+        private P priority;
+        private long seq;
+
+        Node(K key, P priority, long seq){
+            this.key = key;
+            this.priority = priority;
+            this.seq = seq;
+        }
+
+        public K getKey(){ return key;}
+
+        public P getPriority(){ return priority;}
+    }
+
+    // Returns true if the key was not queued before.
+    public boolean push(K key, P priority){
+        Integer slot = slots.get(key);
+        if (slot != null){
+            Node<K, P> node = heap.get(slot);
+            node.seq = nextSeq++;
+            reprioritize(slot, priority);
+            return false;
+        }
+        heap.add(new Node<K, P>(key, priority, nextSeq++));
+        slots.put(key, heap.size() - 1);
+        siftUp(heap.size() - 1);
+        return true;
+    }
+
+    public Node<K, P> peek(){
+        return heap.isEmpty() ? null : heap.get(0);
+    }
+
+    public Node<K, P> pop(){
+        return heap.isEmpty() ? null : removeAt(0);
+    }
+
+    // Returns false if the key is not queued.
+    public boolean updatePriority(K key, P priority){
+        Integer slot = slots.get(key);
+        if (slot == null){
+            return false;
+        }
+        reprioritize(slot, priority);
+        return true;
+    }
+
+    // Returns the removed key's priority, or null if it was not queued.
+    public P remove(K key){
+        Integer slot = slots.get(key);
+        return slot == null ? null : removeAt(slot).priority;
+    }
+
+    public P priorityOf(K key){
+        Integer slot = slots.get(key);
+        return slot == null ? null : heap.get(slot).priority;
+    }
+
+    public boolean contains(K key){
+        return slots.containsKey(key);
+    }
+
+    public int size(){
+        return heap.size();
+    }
+
+    public boolean isEmpty(){
+        return heap.isEmpty();
+    }
+
+    public void clear(){
+        heap.clear();
+        slots.clear();
+    }
+
+    private void reprioritize(int slot, P priority){
+        heap.get(slot).priority = priority;
+        siftDown(siftUp(slot));
+    }
+
+    private Node<K, P> removeAt(int slot){
+        Node<K, P> node = heap.get(slot);
+        int last = heap.size() - 1;
+        swap(slot, last);
+        heap.remove(last);
+        slots.remove(node.key);
+        if (slot < heap.size()){
+            siftDown(siftUp(slot));
+        }
+        return node;
+    }
+
+    private boolean less(int a, int b){
+        Node<K, P> x = heap.get(a);
+        Node<K, P> y = heap.get(b);
+        int c = x.priority.compareTo(y.priority);
+        return c != 0 ? c < 0 : x.seq < y.seq;
+    }
+
+    private int siftUp(int slot){
+        while (slot > 0){
+            int parent = (slot - 1) / 2;
+            if (!less(slot, parent)){
+                break;
+            }
+            swap(slot, parent);
+            slot = parent;
+        }
+        return slot;
+    }
+
+    private void siftDown(int slot){
+        int n = heap.size();
+        while (true){
+            int left = 2 * slot + 1;
+            if (left >= n){
+                return;
+            }
+            int child = left + 1 < n && less(left + 1, left) ? left + 1 : left;
+            if (!less(child, slot)){
+                return;
+            }
+            swap(slot, child);
+            slot = child;
+        }
+    }
+
+    private void swap(int a, int b){
+        if (a == b){
+            return;
+        }
+        Node<K, P> x = heap.get(a);
+        Node<K, P> y = heap.get(b);
+        heap.set(a, y);
+        heap.set(b, x);
+        slots.put(y.key, a);
+        slots.put(x.key, b);
+    }
+}
@@ -0,0 +1,311 @@
+package Util;
+
+// Linear Kalman filters. Both variants can gate outliers: a measurement whose
+// innovation is more than gateSigmas standard deviations away (Mahalanobis
+// distance for the multi-dimensional filter) is skipped and counted.
+public final class Kalman {
+    private Kalman(){}
+
+    // Scalar random-walk filter: predict adds process noise q per step,
+    // update blends in a measurement with noise variance r.
+    public static class Kalman1D {
+        private final double q;
+        private final double r;
+        private double x;
+        private double p;
+        private double gateSigmas = Double.POSITIVE_INFINITY;
+        private long gated;
+
+        public Kalman1D(double initial, double initialVariance, double q, double r){
+            this.x = initial;
+            this.p = initialVariance;
+            this.q = q;
+            this.r = r;
+        }
+
+        public void setGate(double sigmas){
+            this.gateSigmas = sigmas;
+        }
+
+        public void predict(){
+            p += q;
+        }
+
+        // Returns false when the measurement was rejected by the gate.
+        public boolean update(double z){
+            double innovation = z - x;
+            double s = p + r;
+            if (Math.abs(innovation) > gateSigmas * Math.sqrt(s)){
+                gated++;
+                return false;
+            }
+            double k = p / s;
+            x += k * innovation;
+            // Joseph form, which for the scalar case is (1-k)^2 p + k^2 r.
+            p = (1 - k) * (1 - k) * p + k * k * r;
+            return true;
+        }
+
+        public double getState(){ return x;}
+
+        public double getVariance(){ return p;}
+
+        public long getGated(){ return gated;}
+    }
+
+    // Filter with n states and m measurements. All matrices are row-major
+    // double[][] owned by the filter; set them through the setters or edit
+    // them in place. Work buffers are allocated once, so predict and update
+    // do not allocate.
+    public static class KalmanN {
+        private final int n;
+        private final int m;
+        private final double[] x;
+        private final double[][] p;
+        private final double[][] f;
+        private final double[][] q;
+        private final double[][] h;
+        private final double[][] r;
+        private double gateSigmas = Double.POSITIVE_INFINITY;
+        private long gated;
+
+        private final double[] xTmp;
+        private final double[] y;
+        private final double[] sy;
+        private final double[][] nn1;
+        private final double[][] nn2;
+        private final double[][] mn;
+        private final double[][] nm;
+        private final double[][] s;
+        private final double[][] sInv;
+        private final double[][] gauss;
+        private final double[][] k;
+
+        public KalmanN(int n, int m){
+            this.n = n;
+            this.m = m;
+            x = new double[n];
+            p = identity(n);
+            f = identity(n);
+            q = new double[n][n];
+            h = new double[m][n];
+            r = identity(m);
+            xTmp = new double[n];
+            y = new double[m];
+            sy = new double[m];
+            nn1 = new double[n][n];
+            nn2 = new double[n][n];
+            mn = new double[m][n];
+            nm = new double[n][m];
+            s = new double[m][m];
+            sInv = new double[m][m];
+            gauss = new double[m][2 * m];
+            k = new double[n][m];
+        }
+
+        public void setState(double[] val){ copy(val, x);}
+
+        public void setCovariance(double[][] val){ copy(val, p);}
+
+        public void setTransition(double[][] val){ copy(val, f);}
+
+        public void setProcessNoise(double[][] val){ copy(val, q);}
+
+        public void setObservation(double[][] val){ copy(val, h);}
+
+        public void setMeasurementNoise(double[][] val){ copy(val, r);}
+
+        public void setGate(double sigmas){
+            this.gateSigmas = sigmas;
+        }
+
+        // x = F x, P = F P F^T + Q
+        public void predict(){
+            for (int i = 0; i < n; i++){
+                double sum = 0;
+                for (int j = 0; j < n; j++){
+                    sum += f[i][j] * x[j];
+                }
+                xTmp[i] = sum;
+            }
+            System.arraycopy(xTmp, 0, x, 0, n);
+            mul(f, p, nn1);
+            mulTransposed(nn1, f, p);
+            for (int i = 0; i < n; i++){
+                for (int j = 0; j < n; j++){
+                    p[i][j] += q[i][j];
+                }
+            }
+        }
+
+        // Returns false when the measurement was rejected by the gate or the
+        // innovation covariance is singular.
+        public boolean update(double[] z){
+            for (int i = 0; i < m; i++){
+                double sum = 0;
+                for (int j = 0; j < n; j++){
+                    sum += h[i][j] * x[j];
+                }
+                y[i] = z[i] - sum;
+            }
+            // S = H P H^T + R
+            mul(h, p, mn);
+            mulTransposed(mn, h, s);
+            for (int i = 0; i < m; i++){
+                for (int j = 0; j < m; j++){
+                    s[i][j] += r[i][j];
+                }
+            }
+            if (!invert(s, sInv, gauss)){
+                return false;
+            }
+            double d2 = 0;
+            for (int i = 0; i < m; i++){
+                double sum = 0;
+                for (int j = 0; j < m; j++){
+                    sum += sInv[i][j] * y[j];
+                }
+                sy[i] = sum;
+                d2 += y[i] * sum;
+            }
+            if (d2 > gateSigmas * gateSigmas){
+                gated++;
+                return false;
+            }
+            // K = P H^T S^-1
+            mulTransposed(p, h, nm);
+            mul(nm, sInv, k);
+            for (int i = 0; i < n; i++){
+                double sum = 0;
+                for (int j = 0; j < m; j++){
+                    sum += k[i][j] * y[j];
+                }
+                x[i] += sum;
+            }
+            // Joseph form: P = (I - K H) P (I - K H)^T + K R K^T
+            mul(k, h, nn1);
+            for (int i = 0; i < n; i++){
+                for (int j = 0; j < n; j++){
+                    nn1[i][j] = (i == j ? 1 : 0) - nn1[i][j];
+                }
+            }
+            mul(nn1, p, nn2);
+            mulTransposed(nn2, nn1, p);
+            mul(k, r, nm);
+            mulTransposed(nm, k, nn1);
+            for (int i = 0; i < n; i++){
+                for (int j = 0; j < n; j++){
+                    p[i][j] += nn1[i][j];
+                }
+            }
+            // Keep P exactly symmetric against rounding drift.
+            for (int i = 0; i < n; i++){
+                for (int j = i + 1; j < n; j++){
+                    double avg = (p[i][j] + p[j][i]) / 2;
+                    p[i][j] = avg;
+                    p[j][i] = avg;
+                }
+            }
+            return true;
+        }
+
+        public double[] getState(){ return x;}
+
+        public double[][] getCovariance(){ return p;}
+
+        public long getGated(){ return gated;}
+    }
+
+    private static double[][] identity(int size){
+        double[][] out = new double[size][size];
+        for (int i = 0; i < size; i++){
+            out[i][i] = 1;
+        }
+        return out;
+    }
+
+    private static void copy(double[] src, double[] dst){
+        if (src.length != dst.length){
+            throw new IllegalArgumentException("Expected length " + dst.length + ", got " + src.length);
+        }
+        System.arraycopy(src, 0, dst, 0, dst.length);
+    }
+
+    private static void copy(double[][] src, double[][] dst){
+        if (src.length != dst.length){
+            throw new IllegalArgumentException("Expected " + dst.length + " rows, got " + src.length);
+        }
+        for (int i = 0; i < dst.length; i++){
+            copy(src[i], dst[i]);
+        }
+    }
+
+    // out = a b
+    private static void mul(double[][] a, double[][] b, double[][] out){
+        int inner = b.length;
+        for (int i = 0; i < out.length; i++){
+            for (int j = 0; j < out[i].length; j++){
+                double sum = 0;
+                for (int l = 0; l < inner; l++){
+                    sum += a[i][l] * b[l][j];
+                }
+                out[i][j] = sum;
+            }
+        }
+    }
+
+    // out = a b^T
+    private static void mulTransposed(double[][] a, double[][] b, double[][] out){
+        int inner = a[0].length;
+        for (int i = 0; i < out.length; i++){
+            for (int j = 0; j < out[i].length; j++){
+                double sum = 0;
+                for (int l = 0; l < inner; l++){
+                    sum += a[i][l] * b[j][l];
+                }
+                out[i][j] = sum;
+            }
+        }
+    }
+
+    // Gauss-Jordan with partial pivoting into a caller-supplied work matrix.
+    private static boolean invert(double[][] a, double[][] out, double[][] work){
+        int size = a.length;
+        for (int i = 0; i < size; i++){
+            for (int j = 0; j < size; j++){
+                work[i][j] = a[i][j];
+                work[i][size + j] = i == j ? 1 : 0;
+            }
+        }
+        for (int col = 0; col < size; col++){
+            int pivot = col;
+            for (int row = col + 1; row < size; row++){
+                if (Math.abs(work[row][col]) > Math.abs(work[pivot][col])){
+                    pivot = row;
+                }
+            }
+            if (Math.abs(work[pivot][col]) < 1e-12){
+                return false;
+            }
+            double[] tmp = work[col];
+            work[col] = work[pivot];
+            work[pivot] = tmp;
+            double div = work[col][col];
+            for (int j = 0; j < 2 * size; j++){
+                work[col][j] /= div;
+            }
+            for (int row = 0; row < size; row++){
+                if (row != col && work[row][col] != 0){
+                    double factor = work[row][col];
+                    for (int j = 0; j < 2 * size; j++){
+                        work[row][j] -= factor * work[col][j];
+                    }
+                }
+            }
+        }
+        for (int i = 0; i < size; i++){
+            System.arraycopy(work[i], size, out[i], 0, size);
+        }
+        return true;
+    }
+}
@@ -0,0 +1,44 @@
+package Util.Logging;
+
+import Util.Enums.LogLevel;
+
+import java.util.Collections;
+import java.util.List;
+import java.util.Map;
+
+public class LogEntry {
+    private final long timestampMs;
+    private final LogLevel level;
+    private final String target;
+    private final String message;
+    private final List<Map.Entry<String, Object>> fields;
+
+    public LogEntry(long timestampMs, LogLevel level, String target, String message,
+                    List<Map.Entry<String, Object>> fields){
+        this.timestampMs = timestampMs;
+        this.level = level;
+        this.target = target;
+        this.message = message;
+        this.fields = Collections.unmodifiableList(fields);
+    }
+
+    public long getTimestampMs(){ return timestampMs;}
+
+    public LogLevel getLevel(){ return level;}
+
+    public String getTarget(){ return target;}
+
+    public String getMessage(){ return message;}
+
+    public List<Map.Entry<String, Object>> getFields(){ return fields;}
+
+    @Override
+    public String toString(){
+        StringBuilder sb = new StringBuilder();
+        sb.append(timestampMs).append(' ').append(level).append(' ').append(target).append(": ").append(message);
+        for (Map.Entry<String, Object> field : fields){
+            sb.append(' ').append(field.getKey()).append('=').append(field.getValue());
+        }
+        return sb.toString();
+    }
+}
@@ -0,0 +1,5 @@
+package Util.Logging;
+
+public interface LogSink {
+    void write(LogEntry entry);
+}
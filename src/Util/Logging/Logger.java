@@ -0,0 +1,117 @@
+package Util.Logging;
+
+import Util.Enums.LogLevel;
+
+import java.util.AbstractMap;
+import java.util.ArrayList;
+import java.util.List;
+import java.util.Map;
+import java.util.concurrent.ConcurrentHashMap;
+import java.util.concurrent.CopyOnWriteArrayList;
+import java.util.concurrent.atomic.AtomicLong;
+import java.util.function.LongSupplier;
+
+// Fields are passed as alternating key/value pairs:
+//   logger.info("nav", "waypoint reached", "index", 3, "error_m", 0.4);
+// The most specific target prefix with a configured level wins, so a level
+// set on "comms" also applies to "comms.mavlink". A trailing value without a
+// key is kept under UNPAIRED_KEY rather than dropped, since logging must not
+// throw at the call site.
+public class Logger {
+    public static final String UNPAIRED_KEY = "_unpaired";
+
+    private static volatile Logger global = new Logger();
+
+    private final List<LogSink> sinks = new CopyOnWriteArrayList<LogSink>();
+    private final Map<String, LogLevel> targetLevels = new ConcurrentHashMap<String, LogLevel>();
+    private final AtomicLong sinkFailures = new AtomicLong();
+    private volatile LogLevel defaultLevel = LogLevel.INFO;
+    private volatile LongSupplier clock = new LongSupplier() {
+        public long getAsLong(){ return System.currentTimeMillis();}
+    };
+
+    public static Logger global(){
+        return global;
+    }
+
+    public static void setGlobal(Logger logger){
+        global = logger;
+    }
+
+    public void addSink(LogSink sink){
+        sinks.add(sink);
+    }
+
+    public void setClock(LongSupplier clock){
+        this.clock = clock;
+    }
+
+    public void setLevel(LogLevel level){
+        this.defaultLevel = level;
+    }
+
+    public void setLevel(String target, LogLevel level){
+        targetLevels.put(target, level);
+    }
+
+    public boolean isEnabled(LogLevel level, String target){
+        String t = target;
+        while (true){
+            LogLevel configured = targetLevels.get(t);
+            if (configured != null){
+                return level.compareTo(configured) >= 0;
+            }
+            int dot = t.lastIndexOf('.');
+            if (dot < 0){
+                return level.compareTo(defaultLevel) >= 0;
+            }
+            t = t.substring(0, dot);
+        }
+    }
+
+    public void log(LogLevel level, String target, String message, Object... keyValues){
+        if (!isEnabled(level, target)){
+            return;
+        }
+        List<Map.Entry<String, Object>> fields = new ArrayList<Map.Entry<String, Object>>();
+        for (int i = 0; i + 1 < keyValues.length; i += 2){
+            fields.add(new AbstractMap.SimpleImmutableEntry<String, Object>(String.valueOf(keyValues[i]), keyValues[i + 1]));
+        }
+        if (keyValues.length % 2 != 0){
+            fields.add(new AbstractMap.SimpleImmutableEntry<String, Object>(UNPAIRED_KEY, keyValues[keyValues.length - 1]));
+        }
+        LogEntry entry = new LogEntry(clock.getAsLong(), level, target, message, fields);
+        for (LogSink sink : sinks){
+            // A broken sink must never take the caller down with it.
+            try {
+                sink.write(entry);
+            } catch (RuntimeException e){
+                sinkFailures.incrementAndGet();
+            }
+        }
+    }
+
+    public void trace(String target, String message, Object... keyValues){
+        log(LogLevel.TRACE, target, message, keyValues);
+    }
+
+    public void debug(String target, String message, Object... keyValues){
+        log(LogLevel.DEBUG, target, message, keyValues);
+    }
+
+    public void info(String target, String message, Object... keyValues){
+        log(LogLevel.INFO, target, message, keyValues);
+    }
+
+    public void warn(String target, String message, Object... keyValues){
+        log(LogLevel.WARN, target, message, keyValues);
+    }
+
+    public void error(String target, String message, Object... keyValues){
+        log(LogLevel.ERROR, target, message, keyValues);
+    }
+
+    public long getSinkFailures(){
+        return sinkFailures.get();
+    }
+}
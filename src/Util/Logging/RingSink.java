@@ -0,0 +1,40 @@
+package Util.Logging;
+
+import java.util.ArrayDeque;
+import java.util.ArrayList;
+import java.util.Deque;
+import java.util.List;
+
+// Keeps the most recent entries in memory, for tests and the flight recorder.
+public class RingSink implements LogSink {
+    private final int capacity;
+    private final Deque<LogEntry> entries = new ArrayDeque<LogEntry>();
+    private long overwritten;
+
+    public RingSink(int capacity){
+        if (capacity <= 0){
+            throw new IllegalArgumentException("Capacity must be positive : " + capacity);
+        }
+        this.capacity = capacity;
+    }
+
+    public synchronized void write(LogEntry entry){
+        if (entries.size() >= capacity){
+            entries.pollFirst();
+            overwritten++;
+        }
+        entries.addLast(entry);
+    }
+
+    public synchronized List<LogEntry> getEntries(){
+        return new ArrayList<LogEntry>(entries);
+    }
+
+    public synchronized long getOverwritten(){
+        return overwritten;
+    }
+
+    public synchronized void clear(){
+        entries.clear();
+    }
+}
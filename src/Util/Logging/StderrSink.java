@@ -0,0 +1,7 @@
+package Util.Logging;
+
+public class StderrSink implements LogSink {
+    public void write(LogEntry entry){
+        System.err.println(entry);
+    }
+}
@@ -0,0 +1,121 @@
+package Util.Logging;
+
+import Util.Rate.Throttle;
+
+import java.util.AbstractMap;
+import java.util.ArrayList;
+import java.util.Collections;
+import java.util.HashMap;
+import java.util.Iterator;
+import java.util.List;
+import java.util.Map;
+
+// Spam suppression in front of another sink. Entries with the same level,
+// target and message are passed at most once per intervalMs; the next one
+// that gets through is preceded by a "N similar messages suppressed" entry.
+// Messages quiet for a full interval have their pending suppressions reported
+// and are forgotten, both by flush() and, at most once per interval, by write(),
+// so the key map stays bounded without explicit flushing.
+public class SuppressingSink implements LogSink {
+    private final LogSink inner;
+    private final long intervalMs;
+    private final Map<String, State> states = new HashMap<String, State>();
+    private long totalSuppressed;
+    private long lastSweepMs;
+
+    private static class State {
+        final Throttle throttle;
+        LogEntry lastSuppressed;
+        long lastSeenMs;
+
+        State(long intervalMs){
+            this.throttle = new Throttle(intervalMs);
+        }
+    }
+
+    public SuppressingSink(LogSink inner, long intervalMs){
+        this.inner = inner;
+        this.intervalMs = intervalMs;
+    }
+
+    public void write(LogEntry entry){
+        List<LogEntry> summaries = new ArrayList<LogEntry>();
+        LogEntry summary = null;
+        boolean pass;
+        synchronized (this){
+            if (entry.getTimestampMs() - lastSweepMs >= intervalMs){
+                evictQuiet(entry.getTimestampMs(), summaries);
+                lastSweepMs = entry.getTimestampMs();
+            }
+            String key = entry.getLevel() + "\u0000" + entry.getTarget() + "\u0000" + entry.getMessage();
+            State state = states.get(key);
+            if (state == null){
+                state = new State(intervalMs);
+                states.put(key, state);
+            }
+            state.lastSeenMs = entry.getTimestampMs();
+            pass = state.throttle.tryEmit(entry.getTimestampMs());
+            if (pass){
+                summary = summarize(state, entry.getTimestampMs());
+            } else {
+                totalSuppressed++;
+                state.lastSuppressed = entry;
+            }
+        }
+        for (LogEntry quiet : summaries){
+            inner.write(quiet);
+        }
+        if (summary != null){
+            inner.write(summary);
+        }
+        if (pass){
+            inner.write(entry);
+        }
+    }
+
+    // Emits summaries for messages quiet for a full interval and forgets them.
+    public void flush(long nowMs){
+        List<LogEntry> summaries = new ArrayList<LogEntry>();
+        synchronized (this){
+            evictQuiet(nowMs, summaries);
+        }
+        for (LogEntry summary : summaries){
+            inner.write(summary);
+        }
+    }
+
+    public synchronized int getTracked(){
+        return states.size();
+    }
+
+    public synchronized long getTotalSuppressed(){
+        return totalSuppressed;
+    }
+
+    private void evictQuiet(long nowMs, List<LogEntry> summaries){
+        Iterator<State> it = states.values().iterator();
+        while (it.hasNext()){
+            State state = it.next();
+            if (nowMs - state.lastSeenMs < intervalMs){
+                continue;
+            }
+            LogEntry summary = summarize(state, nowMs);
+            if (summary != null){
+                summaries.add(summary);
+            }
+            it.remove();
+        }
+    }
+
+    private static LogEntry summarize(State state, long nowMs){
+        long count = state.throttle.takeSuppressed();
+        LogEntry like = state.lastSuppressed;
+        state.lastSuppressed = null;
+        if (count == 0){
+            return null;
+        }
+        List<Map.Entry<String, Object>> fields = Collections.<Map.Entry<String, Object>>singletonList(
+                new AbstractMap.SimpleImmutableEntry<String, Object>("message", like.getMessage()));
+        return new LogEntry(nowMs, like.getLevel(), like.getTarget(), count + " similar messages suppressed", fields);
+    }
+}
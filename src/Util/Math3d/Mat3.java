@@ -0,0 +1,58 @@
+package Util.Math3d;
+
+// Row-major 3x3 matrix.
+public final class Mat3 {
+    public static final Mat3 IDENTITY = new Mat3(new float[]{1, 0, 0, 0, 1, 0, 0, 0, 1});
+
+    private final float[] m;
+
+    public Mat3(float[] rowMajor){
+        if (rowMajor.length != 9){
+            throw new IllegalArgumentException("Mat3 needs 9 elements, got " + rowMajor.length);
+        }
+        this.m = rowMajor.clone();
+    }
+
+    public float get(int row, int col){
+        return m[row * 3 + col];
+    }
+
+    public Mat3 multiply(Mat3 o){
+        float[] r = new float[9];
+        for (int i = 0; i < 3; i++){
+            for (int j = 0; j < 3; j++){
+                r[i * 3 + j] = m[i * 3] * o.m[j] + m[i * 3 + 1] * o.m[3 + j] + m[i * 3 + 2] * o.m[6 + j];
+            }
+        }
+        return new Mat3(r);
+    }
+
+    public Vec3 multiply(Vec3 v){
+        return new Vec3(m[0] * v.x + m[1] * v.y + m[2] * v.z,
+                m[3] * v.x + m[4] * v.y + m[5] * v.z,
+                m[6] * v.x + m[7] * v.y + m[8] * v.z);
+    }
+
+    public Mat3 transpose(){
+        return new Mat3(new float[]{m[0], m[3], m[6], m[1], m[4], m[7], m[2], m[5], m[8]});
+    }
+
+    public static Mat3 fromQuat(Quat q){
+        Quat n = q.normalize();
+        float w = n.w, x = n.x, y = n.y, z = n.z;
+        return new Mat3(new float[]{
+                1 - 2 * (y * y + z * z), 2 * (x * y - w * z), 2 * (x * z + w * y),
+                2 * (x * y + w * z), 1 - 2 * (x * x + z * z), 2 * (y * z - w * x),
+                2 * (x * z - w * y), 2 * (y * z + w * x), 1 - 2 * (x * x + y * y)
+        });
+    }
+
+    public boolean approxEq(Mat3 o, float eps){
+        for (int i = 0; i < 9; i++){
+            if (Math.abs(m[i] - o.m[i]) > eps){
+                return false;
+            }
+        }
+        return true;
+    }
+}
@@ -0,0 +1,132 @@
+package Util.Math3d;
+
+// Unit quaternion rotations, Hamilton convention. Euler angles are
+// aerospace ZYX order in radians: roll about x, pitch about y, yaw about z.
+public final class Quat {
+    public static final Quat IDENTITY = new Quat(1, 0, 0, 0);
+
+    public final float w;
+    public final float x;
+    public final float y;
+    public final float z;
+
+    public Quat(float w, float x, float y, float z){
+        this.w = w;
+        this.x = x;
+        this.y = y;
+        this.z = z;
+    }
+
+    public float norm(){
+        return (float) Math.sqrt(w * w + x * x + y * y + z * z);
+    }
+
+    // A degenerate quaternion normalizes to the identity rotation.
+    public Quat normalize(){
+        float n = norm();
+        if (n < 1e-12f || Float.isNaN(n) || Float.isInfinite(n)){
+            return IDENTITY;
+        }
+        return new Quat(w / n, x / n, y / n, z / n);
+    }
+
+    public Quat conjugate(){
+        return new Quat(w, -x, -y, -z);
+    }
+
+    public Quat multiply(Quat o){
+        return new Quat(w * o.w - x * o.x - y * o.y - z * o.z,
+                w * o.x + x * o.w + y * o.z - z * o.y,
+                w * o.y - x * o.z + y * o.w + z * o.x,
+                w * o.z + x * o.y - y * o.x + z * o.w);
+    }
+
+    public Vec3 rotate(Vec3 v){
+        Quat p = multiply(new Quat(0, v.x, v.y, v.z)).multiply(conjugate());
+        return new Vec3(p.x, p.y, p.z);
+    }
+
+    public static Quat fromAxisAngle(Vec3 axis, float angle){
+        Vec3 a = axis.normalize();
+        if (a == Vec3.ZERO){
+            return IDENTITY;
+        }
+        float s = (float) Math.sin(angle / 2);
+        return new Quat((float) Math.cos(angle / 2), a.x * s, a.y * s, a.z * s);
+    }
+
+    public static Quat fromEuler(float roll, float pitch, float yaw){
+        double cr = Math.cos(roll / 2), sr = Math.sin(roll / 2);
+        double cp = Math.cos(pitch / 2), sp = Math.sin(pitch / 2);
+        double cy = Math.cos(yaw / 2), sy = Math.sin(yaw / 2);
+        return new Quat((float) (cr * cp * cy + sr * sp * sy),
+                (float) (sr * cp * cy - cr * sp * sy),
+                (float) (cr * sp * cy + sr * cp * sy),
+                (float) (cr * cp * sy - sr * sp * cy));
+    }
+
+    // Returns {roll, pitch, yaw}. At pitch = +/-90 degrees roll and yaw are
+    // coupled (gimbal lock); roll is reported as zero and yaw absorbs it.
+    public float[] toEuler(){
+        Quat q = normalize();
+        double sinp = 2 * (q.w * q.y - q.z * q.x);
+        if (Math.abs(sinp) >= 0.99999){
+            double pitch = Math.copySign(Math.PI / 2, sinp);
+            double yaw = -2 * Math.copySign(1, sinp) * Math.atan2(q.x, q.w);
+            return new float[]{0, (float) pitch, (float) wrap(yaw)};
+        }
+        double roll = Math.atan2(2 * (q.w * q.x + q.y * q.z), 1 - 2 * (q.x * q.x + q.y * q.y));
+        double pitch = Math.asin(sinp);
+        double yaw = Math.atan2(2 * (q.w * q.z + q.x * q.y), 1 - 2 * (q.y * q.y + q.z * q.z));
+        return new float[]{(float) roll, (float) pitch, (float) yaw};
+    }
+
+    public Mat3 toMat3(){
+        return Mat3.fromQuat(this);
+    }
+
+    // Takes the short way round and falls back to normalized lerp when the
+    // inputs are nearly parallel.
+    public static Quat slerp(Quat a, Quat b, float t){
+        Quat qa = a.normalize();
+        Quat qb = b.normalize();
+        float dot = qa.w * qb.w + qa.x * qb.x + qa.y * qb.y + qa.z * qb.z;
+        if (dot < 0){
+            qb = new Quat(-qb.w, -qb.x, -qb.y, -qb.z);
+            dot = -dot;
+        }
+        if (dot > 0.9995f){
+            return new Quat(qa.w + t * (qb.w - qa.w), qa.x + t * (qb.x - qa.x),
+                    qa.y + t * (qb.y - qa.y), qa.z + t * (qb.z - qa.z)).normalize();
+        }
+        double theta = Math.acos(dot);
+        double sin = Math.sin(theta);
+        float wa = (float) (Math.sin((1 - t) * theta) / sin);
+        float wb = (float) (Math.sin(t * theta) / sin);
+        return new Quat(wa * qa.w + wb * qb.w, wa * qa.x + wb * qb.x, wa * qa.y + wb * qb.y, wa * qa.z + wb * qb.z);
+    }
+
+    // q and -q are the same rotation.
+    public boolean approxEq(Quat o, float eps){
+        boolean same = Math.abs(w - o.w) <= eps && Math.abs(x - o.x) <= eps
+                && Math.abs(y - o.y) <= eps && Math.abs(z - o.z) <= eps;
+        boolean negated = Math.abs(w + o.w) <= eps && Math.abs(x + o.x) <= eps
+                && Math.abs(y + o.y) <= eps && Math.abs(z + o.z) <= eps;
+        return same || negated;
+    }
+
+    private static double wrap(double angle){
+        while (angle > Math.PI){
+            angle -= 2 * Math.PI;
+        }
+        while (angle < -Math.PI){
+            angle += 2 * Math.PI;
+        }
+        return angle;
+    }
+
+    @Override
+    public String toString(){
+        return "(" + w + ", " + x + ", " + y + ", " + z + ")";
+    }
+}
@@ -0,0 +1,51 @@
+package Util.Math3d;
+
+public final class Vec3 {
+    public static final Vec3 ZERO = new Vec3(0, 0, 0);
+    public static final Vec3 UNIT_X = new Vec3(1, 0, 0);
+    public static final Vec3 UNIT_Y = new Vec3(0, 1, 0);
+    public static final Vec3 UNIT_Z = new Vec3(0, 0, 1);
+
+    public final float x;
+    public final float y;
+    public final float z;
+
+    public Vec3(float x, float y, float z){
+        this.x = x;
+        this.y = y;
+        this.z = z;
+    }
+
+    public Vec3 add(Vec3 o){ return new Vec3(x + o.x, y + o.y, z + o.z);}
+
+    public Vec3 sub(Vec3 o){ return new Vec3(x - o.x, y - o.y, z - o.z);}
+
+    public Vec3 scale(float s){ return new Vec3(x * s, y * s, z * s);}
+
+    public float dot(Vec3 o){ return x * o.x + y * o.y + z * o.z;}
+
+    // Right-handed: UNIT_X.cross(UNIT_Y) == UNIT_Z.
+    public Vec3 cross(Vec3 o){
+        return new Vec3(y * o.z - z * o.y, z * o.x - x * o.z, x * o.y - y * o.x);
+    }
+
+    public float norm(){ return (float) Math.sqrt(dot(this));}
+
+    // A zero-length (or non-finite) vector normalizes to ZERO instead of NaN.
+    public Vec3 normalize(){
+        float n = norm();
+        if (n < 1e-12f || Float.isNaN(n) || Float.isInfinite(n)){
+            return ZERO;
+        }
+        return scale(1 / n);
+    }
+
+    public boolean approxEq(Vec3 o, float eps){
+        return Math.abs(x - o.x) <= eps && Math.abs(y - o.y) <= eps && Math.abs(z - o.z) <= eps;
+    }
+
+    @Override
+    public String toString(){
+        return "(" + x + ", " + y + ", " + z + ")";
+    }
+}
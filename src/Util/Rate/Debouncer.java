@@ -0,0 +1,45 @@
+package Util.Rate;
+
+// Holds the latest submitted value and releases it once no new value has
+// arrived for quietMs. Each burst yields exactly one value. Null is a valid
+// value; callers that submit nulls should check isReady() before poll().
+public class Debouncer<T> {
+    private final long quietMs;
+    private T value;
+    private boolean pending;
+    private long lastSubmitMs;
+
+    public Debouncer(long quietMs){
+        this.quietMs = quietMs;
+    }
+
+    public void submit(T value, long nowMs){
+        this.value = value;
+        pending = true;
+        lastSubmitMs = nowMs;
+    }
+
+    // Returns the settled value once, or null while the burst is ongoing.
+    public T poll(long nowMs){
+        if (!isReady(nowMs)){
+            return null;
+        }
+        T settled = value;
+        value = null;
+        pending = false;
+        return settled;
+    }
+
+    public boolean isReady(long nowMs){
+        return pending && nowMs - lastSubmitMs >= quietMs;
+    }
+
+    public boolean isPending(){
+        return pending;
+    }
+
+    public void reset(){
+        value = null;
+        pending = false;
+    }
+}
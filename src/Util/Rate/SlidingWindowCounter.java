@@ -0,0 +1,60 @@
+package Util.Rate;
+
+import java.util.Arrays;
+
+// Counts events in the trailing windowMs, split into buckets sub-windows.
+// The window moves one bucket at a time, so the count can include events up
+// to one bucket width older than windowMs. Updates are amortized O(1).
+public class SlidingWindowCounter {
+    private final long bucketMs;
+    private final long[] counts;
+    private long total;
+    private long headIndex;
+
+    public SlidingWindowCounter(long windowMs, int buckets){
+        if (buckets <= 0 || windowMs < buckets){
+            throw new IllegalArgumentException("Need at least 1 ms per bucket");
+        }
+        this.bucketMs = windowMs / buckets;
+        this.counts = new long[buckets];
+    }
+
+    public void record(long n, long nowMs){
+        advance(nowMs);
+        counts[(int) (headIndex % counts.length)] += n;
+        total += n;
+    }
+
+    public void record(long nowMs){
+        record(1, nowMs);
+    }
+
+    public long count(long nowMs){
+        advance(nowMs);
+        return total;
+    }
+
+    // Events per second over the whole window.
+    public double rate(long nowMs){
+        return count(nowMs) * 1000.0 / (bucketMs * counts.length);
+    }
+
+    public void reset(){
+        Arrays.fill(counts, 0);
+        total = 0;
+    }
+
+    private void advance(long nowMs){
+        long index = nowMs / bucketMs;
+        if (index <= headIndex){
+            return;
+        }
+        long steps = Math.min(index - headIndex, counts.length);
+        for (long i = 1; i <= steps; i++){
+            int slot = (int) ((headIndex + i) % counts.length);
+            total -= counts[slot];
+            counts[slot] = 0;
+        }
+        headIndex = index;
+    }
+}
@@ -0,0 +1,37 @@
+package Util.Rate;
+
+// Lets the first event through and then at most one per intervalMs.
+public class Throttle {
+    private final long intervalMs;
+    private long lastEmitMs;
+    private boolean emitted;
+    private long suppressed;
+
+    public Throttle(long intervalMs){
+        this.intervalMs = intervalMs;
+    }
+
+    public boolean tryEmit(long nowMs){
+        if (emitted && nowMs - lastEmitMs < intervalMs){
+            suppressed++;
+            return false;
+        }
+        emitted = true;
+        lastEmitMs = nowMs;
+        return true;
+    }
+
+    // Events refused since the last takeSuppressed() or reset().
+    public long getSuppressed(){ return suppressed;}
+
+    public long takeSuppressed(){
+        long n = suppressed;
+        suppressed = 0;
+        return n;
+    }
+
+    public void reset(){
+        emitted = false;
+        suppressed = 0;
+    }
+}
@@ -0,0 +1,64 @@
+package Util.Rate;
+
+// Holds up to capacity tokens, refilled continuously at ratePerSec.
+public class TokenBucket {
+    private double capacity;
+    private double ratePerSec;
+    private double tokens;
+    private long lastMs;
+
+    public TokenBucket(double capacity, double ratePerSec, long nowMs){
+        this.capacity = capacity;
+        this.ratePerSec = ratePerSec;
+        this.tokens = capacity;
+        this.lastMs = nowMs;
+    }
+
+    public boolean tryConsume(double amount, long nowMs){
+        refill(nowMs);
+        if (tokens < amount){
+            return false;
+        }
+        tokens -= amount;
+        return true;
+    }
+
+    public double available(long nowMs){
+        refill(nowMs);
+        return tokens;
+    }
+
+    // Tokens earned at the old rate are credited before the new rate applies.
+    public void setRate(double ratePerSec, double capacity, long nowMs){
+        refill(nowMs);
+        this.ratePerSec = ratePerSec;
+        this.capacity = capacity;
+        tokens = Math.min(tokens, capacity);
+    }
+
+    public void fill(long nowMs){
+        refill(nowMs);
+        tokens = capacity;
+    }
+
+    public double getCapacity(){
+        return capacity;
+    }
+
+    public double getRatePerSec(){
+        return ratePerSec;
+    }
+
+    // Removes tokens without checking the balance, which may go negative;
+    // the bucket then has to refill past zero before it grants again.
+    public void take(double amount){
+        tokens -= amount;
+    }
+
+    private void refill(long nowMs){
+        if (nowMs > lastMs){
+            tokens = Math.min(capacity, tokens + (nowMs - lastMs) * ratePerSec / 1000.0);
+            lastMs = nowMs;
+        }
+    }
+}
@@ -0,0 +1,106 @@
+package Util;
+
+import Util.Enums.OverflowPolicy;
+
+import java.util.Iterator;
+import java.util.NoSuchElementException;
+
+// Fixed-capacity FIFO that never allocates after construction. When full,
+// push either refuses the element or overwrites the oldest one.
+public class RingBuffer<T> implements Iterable<T> {
+    private final Object[] slots;
+    private final OverflowPolicy policy;
+    private int head;
+    private int size;
+
+    public RingBuffer(int capacity, OverflowPolicy policy){
+        if (capacity <= 0){
+            throw new IllegalArgumentException("Capacity must be positive : " + capacity);
+        }
+        this.slots = new Object[capacity];
+        this.policy = policy;
+    }
+
+    // Returns false only when full under REJECT.
+    public boolean push(T value){
+        if (size == slots.length){
+            if (policy == OverflowPolicy.REJECT){
+                return false;
+            }
+            slots[head] = value;
+            head = (head + 1) % slots.length;
+            return true;
+        }
+        slots[(head + size) % slots.length] = value;
+        size++;
+        return true;
+    }
+
+    @SuppressWarnings("unchecked")
+    public T pop(){
+        if (size == 0){
+            return null;
+        }
+        T value = (T) slots[head];
+        slots[head] = null;
+        head = (head + 1) % slots.length;
+        size--;
+        return value;
+    }
+
+    @SuppressWarnings("unchecked")
+    public T peek(){
+        return size == 0 ? null : (T) slots[head];
+    }
+
+    @SuppressWarnings("unchecked")
+    public T get(int index){
+        if (index < 0 || index >= size){
+            throw new IndexOutOfBoundsException("Index " + index + " of " + size);
+        }
+        return (T) slots[(head + index) % slots.length];
+    }
+
+    public int len(){
+        return size;
+    }
+
+    public boolean isEmpty(){
+        return size == 0;
+    }
+
+    public boolean isFull(){
+        return size == slots.length;
+    }
+
+    public int capacity(){
+        return slots.length;
+    }
+
+    public void clear(){
+        while (size > 0){
+            pop();
+        }
+    }
+
+    public Iterator<T> iterator(){
+        return new Iterator<T>() {
+            private int index;
+
+            public boolean hasNext(){
+                return index < size;
+            }
+
+            public T next(){
+                if (!hasNext()){
+                    throw new NoSuchElementException();
+                }
+                return get(index++);
+            }
+
+            public void remove(){
+                throw new UnsupportedOperationException();
+            }
+        };
+    }
+}
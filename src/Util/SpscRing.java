@@ -0,0 +1,67 @@
+package Util;
+
+import java.util.concurrent.atomic.AtomicLong;
+import java.util.concurrent.atomic.AtomicReferenceArray;
+
+// Lock-free ring for exactly one producer thread and one consumer thread.
+// Each side only writes its own index, publishing it with a release store.
+public class SpscRing<T> {
+    private final AtomicReferenceArray<T> slots;
+    private final AtomicLong head = new AtomicLong();
+    private final AtomicLong tail = new AtomicLong();
+    private final Producer producer = new Producer();
+    private final Consumer consumer = new Consumer();
+
+    public SpscRing(int capacity){
+        if (capacity <= 0){
+            throw new IllegalArgumentException("Capacity must be positive : " + capacity);
+        }
+        this.slots = new AtomicReferenceArray<T>(capacity);
+    }
+
+    public Producer producer(){
+        return producer;
+    }
+
+    public Consumer consumer(){
+        return consumer;
+    }
+
+    public int capacity(){
+        return slots.length();
+    }
+
+    public class Producer {
+        private Producer(){}
+
+        public boolean offer(T value){
+            long t = tail.get();
+            if (t - head.get() >= slots.length()){
+                return false;
+            }
+            slots.lazySet((int) (t % slots.length()), value);
+            tail.lazySet(t + 1);
+            return true;
+        }
+    }
+
+    public class Consumer {
+        private Consumer(){}
+
+        public T poll(){
+            long h = head.get();
+            if (h >= tail.get()){
+                return null;
+            }
+            int index = (int) (h % slots.length());
+            T value = slots.get(index);
+            slots.lazySet(index, null);
+            head.lazySet(h + 1);
+            return value;
+        }
+
+        public int size(){
+            return (int) (tail.get() - head.get());
+        }
+    }
+}
@@ -0,0 +1,196 @@
+package Util;
+
+import Util.Enums.OverflowPolicy;
+import Util.Enums.TransitionResult;
+
+import java.util.ArrayList;
+import java.util.HashMap;
+import java.util.List;
+import java.util.Map;
+import java.util.function.Consumer;
+import java.util.function.Predicate;
+
+// Table-driven state machine with one level of hierarchy: a transition
+// declared on a parent state applies to all of its children unless a child
+// declares its own. Guards and actions get the caller's context. On a
+// transition the states between the source and the least common ancestor of
+// source and target are exited (child first), then the transition action
+// runs, then the states below that ancestor down to the target are entered
+// (parent first). Moving between a child and its own parent therefore never
+// exits or re-enters the parent. A self transition exits and re-enters the
+// state itself.
+public class StateMachine<S, E, C> {
+    private final Map<S, Map<E, Transition<S, C>>> table;
+    private final Map<S, S> parents;
+    private final Map<S, Consumer<C>> entryActions;
+    private final Map<S, Consumer<C>> exitActions;
+    private final RingBuffer<Record<S, E>> history;
+    private S state;
+
+    private static class Transition<S, C> {
+        final S target;
+        final Predicate<C> guard;
+        final Consumer<C> action;
+
+        Transition(S target, Predicate<C> guard, Consumer<C> action){
+            this.target = target;
+            this.guard = guard;
+            this.action = action;
+        }
+    }
+
+    public static class Record<S, E> {
+        private final S from;
+        private final E event;
+        private final S to;
+        private final TransitionResult result;
+
+        Record(S from, E event, S to, TransitionResult result){
+            this.from = from;
+            this.event = event;
+            this.to = to;
+            this.result = result;
+        }
+
+        public S getFrom(){ return from;}
+
+        public E getEvent(){ return event;}
+
+        public S getTo(){ return to;}
+
+        public TransitionResult getResult(){ return result;}
+    }
+
+    public static class Builder<S, E, C> {
+        private final S initial;
+        private final Map<S, Map<E, Transition<S, C>>> table = new HashMap<S, Map<E, Transition<S, C>>>();
+        private final Map<S, S> parents = new HashMap<S, S>();
+        private final Map<S, Consumer<C>> entryActions = new HashMap<S, Consumer<C>>();
+        private final Map<S, Consumer<C>> exitActions = new HashMap<S, Consumer<C>>();
+        private int historySize = 16;
+
+        public Builder(S initial){
+            this.initial = initial;
+        }
+
+        public Builder<S, E, C> transition(S from, E event, S to){
+            return transition(from, event, to, null, null);
+        }
+
+        public Builder<S, E, C> transition(S from, E event, S to, Predicate<C> guard, Consumer<C> action){
+            Map<E, Transition<S, C>> row = table.get(from);
+            if (row == null){
+                row = new HashMap<E, Transition<S, C>>();
+                table.put(from, row);
+            }
+            if (row.containsKey(event)){
+                throw new IllegalArgumentException("Duplicate transition " + from + " --" + event + "-->");
+            }
+            row.put(event, new Transition<S, C>(to, guard, action));
+            return this;
+        }
+
+        public Builder<S, E, C> parent(S child, S parent){
+            if (parents.containsKey(parent)){
+                throw new IllegalArgumentException("Only one level of hierarchy is supported : " + parent);
+            }
+            parents.put(child, parent);
+            return this;
+        }
+
+        public Builder<S, E, C> onEntry(S state, Consumer<C> action){
+            entryActions.put(state, action);
+            return this;
+        }
+
+        public Builder<S, E, C> onExit(S state, Consumer<C> action){
+            exitActions.put(state, action);
+            return this;
+        }
+
+        public Builder<S, E, C> historySize(int val){
+            historySize = val;
+            return this;
+        }
+
+        public StateMachine<S, E, C> build(){
+            return new StateMachine<S, E, C>(this);
+        }
+    }
+
+    private StateMachine(Builder<S, E, C> builder){
+        table = builder.table;
+        parents = builder.parents;
+        entryActions = builder.entryActions;
+        exitActions = builder.exitActions;
+        history = new RingBuffer<Record<S, E>>(builder.historySize, OverflowPolicy.OVERWRITE);
+        state = builder.initial;
+    }
+
+    public S getState(){
+        return state;
+    }
+
+    public boolean isIn(S s){
+        return state.equals(s) || s.equals(parents.get(state));
+    }
+
+    public TransitionResult handle(E event, C context){
+        Transition<S, C> transition = lookup(state, event);
+        if (transition == null && parents.containsKey(state)){
+            transition = lookup(parents.get(state), event);
+        }
+        if (transition == null){
+            return record(event, state, TransitionResult.NO_TRANSITION);
+        }
+        if (transition.guard != null && !transition.guard.test(context)){
+            return record(event, state, TransitionResult.REJECTED_BY_GUARD);
+        }
+        S from = state;
+        S to = transition.target;
+        boolean self = from.equals(to);
+        for (S s = from; s != null && (self && s.equals(from) || !contains(s, to)); s = parents.get(s)){
+            run(exitActions.get(s), context);
+        }
+        run(transition.action, context);
+        List<S> entered = new ArrayList<S>();
+        for (S s = to; s != null && (self && s.equals(to) || !contains(s, from)); s = parents.get(s)){
+            entered.add(0, s);
+        }
+        for (S s : entered){
+            run(entryActions.get(s), context);
+        }
+        state = to;
+        history.push(new Record<S, E>(from, event, state, TransitionResult.ACCEPTED));
+        return TransitionResult.ACCEPTED;
+    }
+
+    public List<Record<S, E>> getHistory(){
+        List<Record<S, E>> records = new ArrayList<Record<S, E>>();
+        for (Record<S, E> record : history){
+            records.add(record);
+        }
+        return records;
+    }
+
+    // True when outer is inner itself or inner's parent.
+    private boolean contains(S outer, S inner){
+        return outer.equals(inner) || outer.equals(parents.get(inner));
+    }
+
+    private Transition<S, C> lookup(S s, E event){
+        Map<E, Transition<S, C>> row = table.get(s);
+        return row == null ? null : row.get(event);
+    }
+
+    private TransitionResult record(E event, S to, TransitionResult result){
+        history.push(new Record<S, E>(state, event, to, result));
+        return result;
+    }
+
+    private void run(Consumer<C> action, C context){
+        if (action != null){
+            action.accept(context);
+        }
+    }
+}
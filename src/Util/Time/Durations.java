@@ -0,0 +1,37 @@
+package Util.Time;
+
+import java.time.Duration;
+
+public final class Durations {
+    private Durations(){}
+
+    // Millisecond precision, truncated: 1h02m03.450s, 2m03.450s, 3.450s.
+    // Leading zero units are left out; inner ones are padded to two digits.
+    public static String format(Duration duration){
+        StringBuilder out = new StringBuilder();
+        if (duration.isNegative()){
+            out.append('-');
+            duration = duration.negated();
+        }
+        long ms = duration.toMillis();
+        long hours = ms / 3600000;
+        long minutes = ms / 60000 % 60;
+        long seconds = ms / 1000 % 60;
+        long millis = ms % 1000;
+        if (hours > 0){
+            out.append(hours).append('h');
+            out.append(String.format("%02dm%02d", minutes, seconds));
+        } else if (minutes > 0){
+            out.append(minutes).append('m');
+            out.append(String.format("%02d", seconds));
+        } else {
+            out.append(seconds);
+        }
+        out.append(String.format(".%03ds", millis));
+        return out.toString();
+    }
+
+    public static String formatMs(long ms){
+        return format(Duration.ofMillis(ms));
+    }
+}
@@ -0,0 +1,63 @@
+package Util.Time;
+
+import java.time.Duration;
+
+// The one place a tick length is defined. Conversions from ticks to time are
+// exact to the nanosecond (rounded to nearest when the tick length is not a
+// whole number of nanoseconds); conversions from time to ticks round down,
+// or up with ticksCeil for waits that must not end early.
+public final class TickRate {
+    private static final long NANOS_PER_SEC = 1000000000L;
+
+    private final long hz;
+
+    public TickRate(long hz){
+        if (hz <= 0 || hz > NANOS_PER_SEC){
+            throw new IllegalArgumentException("Tick rate must be within 1..1e9 Hz : " + hz);
+        }
+        this.hz = hz;
+    }
+
+    public long getHz(){ return hz;}
+
+    public Duration tickLength(){
+        return toDuration(new Ticks(1));
+    }
+
+    public Duration toDuration(Ticks ticks){
+        long whole = ticks.getValue() / hz;
+        long rest = ticks.getValue() % hz;
+        long nanos = (rest * NANOS_PER_SEC + hz / 2) / hz;
+        return Duration.ofSeconds(whole).plusNanos(nanos);
+    }
+
+    public Ticks ticks(Duration duration){
+        return new Ticks(toTicks(duration, false));
+    }
+
+    public Ticks ticksCeil(Duration duration){
+        return new Ticks(toTicks(duration, true));
+    }
+
+    public Ticks ticksMs(long ms){
+        return ticks(Duration.ofMillis(ms));
+    }
+
+    private long toTicks(Duration duration, boolean ceil){
+        if (duration.isNegative()){
+            throw new IllegalArgumentException("Negative duration : " + duration);
+        }
+        long whole = Math.multiplyExact(duration.getSeconds(), hz);
+        long scaled = duration.getNano() * hz;
+        long part = scaled / NANOS_PER_SEC;
+        if (ceil && scaled % NANOS_PER_SEC != 0){
+            part++;
+        }
+        return Math.addExact(whole, part);
+    }
+
+    @Override
+    public String toString(){
+        return hz + " Hz";
+    }
+}
@@ -0,0 +1,51 @@
+package Util.Time;
+
+// A count of kernel ticks. Only meaningful together with a TickRate.
+public final class Ticks implements Comparable<Ticks> {
+    public static final Ticks ZERO = new Ticks(0);
+
+    private final long value;
+
+    public Ticks(long value){
+        if (value < 0){
+            throw new IllegalArgumentException("Negative tick count : " + value);
+        }
+        this.value = value;
+    }
+
+    public long getValue(){ return value;}
+
+    public Ticks add(Ticks o){
+        return new Ticks(Math.addExact(value, o.value));
+    }
+
+    // Returns null on overflow.
+    public Ticks checkedAdd(Ticks o){
+        long sum = value + o.value;
+        return sum < 0 ? null : new Ticks(sum);
+    }
+
+    // Clamps at zero.
+    public Ticks saturatingSub(Ticks o){
+        return value > o.value ? new Ticks(value - o.value) : ZERO;
+    }
+
+    public int compareTo(Ticks o){
+        return Long.compare(value, o.value);
+    }
+
+    @Override
+    public boolean equals(Object o){
+        return o instanceof Ticks && ((Ticks) o).value == value;
+    }
+
+    @Override
+    public int hashCode(){
+        return Long.hashCode(value);
+    }
+
+    @Override
+    public String toString(){
+        return value + " ticks";
+    }
+}
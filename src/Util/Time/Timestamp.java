@@ -0,0 +1,63 @@
+package Util.Time;
+
+import java.time.Duration;
+import java.util.function.LongSupplier;
+
+// A point in time as nanoseconds on a monotonic clock. Timestamps from
+// different clocks must not be compared.
+public final class Timestamp implements Comparable<Timestamp> {
+    public static final LongSupplier SYSTEM_CLOCK = System::nanoTime;
+
+    private final long nanos;
+
+    public Timestamp(long nanos){
+        this.nanos = nanos;
+    }
+
+    public static Timestamp now(LongSupplier nanoClock){
+        return new Timestamp(nanoClock.getAsLong());
+    }
+
+    public static Timestamp now(){
+        return now(SYSTEM_CLOCK);
+    }
+
+    public long getNanos(){ return nanos;}
+
+    // Returns null on overflow.
+    public Timestamp checkedAdd(Duration duration){
+        try {
+            return new Timestamp(Math.addExact(nanos, duration.toNanos()));
+        } catch (ArithmeticException e){
+            return null;
+        }
+    }
+
+    // Time elapsed since earlier, or zero if earlier is actually later.
+    public Duration since(Timestamp earlier){
+        return nanos > earlier.nanos ? Duration.ofNanos(nanos - earlier.nanos) : Duration.ZERO;
+    }
+
+    public boolean isBefore(Timestamp o){
+        return nanos < o.nanos;
+    }
+
+    public int compareTo(Timestamp o){
+        return Long.compare(nanos, o.nanos);
+    }
+
+    @Override
+    public boolean equals(Object o){
+        return o instanceof Timestamp && ((Timestamp) o).nanos == nanos;
+    }
+
+    @Override
+    public int hashCode(){
+        return Long.hashCode(nanos);
+    }
+
+    @Override
+    public String toString(){
+        return "T+" + Durations.format(Duration.ofNanos(nanos));
+    }
+}
@@ -0,0 +1,39 @@
+package Util;
+
+import java.util.ArrayList;
+import java.util.List;
+
+// Deadline-ordered timers. Rescheduling an id moves its existing entry, and
+// timers with the same deadline fire in the order they were scheduled.
+public class TimerWheel<T> {
+    private final IndexedPriorityQueue<T, Long> queue = new IndexedPriorityQueue<T, Long>();
+
+    public void schedule(T id, long deadlineMs){
+        queue.push(id, deadlineMs);
+    }
+
+    public boolean cancel(T id){
+        return queue.remove(id) != null;
+    }
+
+    // All timers due at nowMs, earliest deadline first.
+    public List<T> expire(long nowMs){
+        List<T> due = new ArrayList<T>();
+        while (!queue.isEmpty() && queue.peek().getPriority() <= nowMs){
+            due.add(queue.pop().getKey());
+        }
+        return due;
+    }
+
+    public Long nextDeadline(){
+        return queue.isEmpty() ? null : queue.peek().getPriority();
+    }
+
+    public boolean contains(T id){
+        return queue.contains(id);
+    }
+
+    public int size(){
+        return queue.size();
+    }
+}
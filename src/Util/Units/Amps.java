@@ -0,0 +1,21 @@
+package Util.Units;
+
+public final class Amps extends Quantity<Amps> {
+    public static final Amps ZERO = new Amps(0);
+
+    private Amps(double value){
+        super(value);
+    }
+
+    public static Amps of(double value){
+        return new Amps(value);
+    }
+
+    public Amps add(Amps o){ return new Amps(value + o.value);}
+
+    public Amps sub(Amps o){ return new Amps(value - o.value);}
+
+    public Amps scale(double factor){ return new Amps(value * factor);}
+
+    protected String symbol(){ return "A";}
+}
@@ -0,0 +1,29 @@
+package Util.Units;
+
+public final class Celsius extends Quantity<Celsius> {
+    public static final double ABSOLUTE_ZERO = -273.15;
+
+    private Celsius(double value){
+        super(value);
+        if (value < ABSOLUTE_ZERO){
+            throw new IllegalArgumentException("Below absolute zero : " + value);
+        }
+    }
+
+    public static Celsius of(double value){
+        return new Celsius(value);
+    }
+
+    public static Celsius fromKelvin(double kelvin){
+        return new Celsius(kelvin + ABSOLUTE_ZERO);
+    }
+
+    public double toKelvin(){ return value - ABSOLUTE_ZERO;}
+
+    // Temperature difference in degrees.
+    public double minus(Celsius o){ return value - o.value;}
+
+    public Celsius plus(double degrees){ return new Celsius(value + degrees);}
+
+    protected String symbol(){ return "C";}
+}
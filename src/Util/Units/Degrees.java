@@ -0,0 +1,29 @@
+package Util.Units;
+
+public final class Degrees extends Quantity<Degrees> {
+    public static final Degrees ZERO = new Degrees(0);
+
+    private Degrees(double value){
+        super(value);
+    }
+
+    public static Degrees of(double value){
+        return new Degrees(value);
+    }
+
+    public Degrees add(Degrees o){ return new Degrees(value + o.value);}
+
+    public Degrees sub(Degrees o){ return new Degrees(value - o.value);}
+
+    public Degrees scale(double factor){ return new Degrees(value * factor);}
+
+    public Radians toRadians(){ return Radians.of(Math.toRadians(value));}
+
+    // Wrapped into [-180, 180).
+    public Degrees normalized(){
+        double d = (value + 180) % 360;
+        return new Degrees((d < 0 ? d + 360 : d) - 180);
+    }
+
+    protected String symbol(){ return "deg";}
+}
@@ -0,0 +1,23 @@
+package Util.Units;
+
+public final class Meters extends Quantity<Meters> {
+    public static final Meters ZERO = new Meters(0);
+
+    private Meters(double value){
+        super(value);
+    }
+
+    public static Meters of(double value){
+        return new Meters(value);
+    }
+
+    public Meters add(Meters o){ return new Meters(value + o.value);}
+
+    public Meters sub(Meters o){ return new Meters(value - o.value);}
+
+    public Meters scale(double factor){ return new Meters(value * factor);}
+
+    public MetersPerSec per(double seconds){ return MetersPerSec.of(value / seconds);}
+
+    protected String symbol(){ return "m";}
+}
@@ -0,0 +1,23 @@
+package Util.Units;
+
+public final class MetersPerSec extends Quantity<MetersPerSec> {
+    public static final MetersPerSec ZERO = new MetersPerSec(0);
+
+    private MetersPerSec(double value){
+        super(value);
+    }
+
+    public static MetersPerSec of(double value){
+        return new MetersPerSec(value);
+    }
+
+    public MetersPerSec add(MetersPerSec o){ return new MetersPerSec(value + o.value);}
+
+    public MetersPerSec sub(MetersPerSec o){ return new MetersPerSec(value - o.value);}
+
+    public MetersPerSec scale(double factor){ return new MetersPerSec(value * factor);}
+
+    public Meters over(double seconds){ return Meters.of(value * seconds);}
+
+    protected String symbol(){ return "m/s";}
+}
@@ -0,0 +1,33 @@
+package Util.Units;
+
+// A percentage validated to 0..100.
+public final class Percent extends Quantity<Percent> {
+    public static final Percent ZERO = new Percent(0);
+    public static final Percent FULL = new Percent(100);
+
+    private Percent(double value){
+        super(value);
+        if (value < 0 || value > 100){
+            throw new IllegalArgumentException("Percent out of range 0..100 : " + value);
+        }
+    }
+
+    public static Percent of(double value){
+        return new Percent(value);
+    }
+
+    public static Percent clamped(double value){
+        if (Double.isNaN(value)){
+            throw new IllegalArgumentException("Not a finite Percent value : " + value);
+        }
+        return new Percent(Math.max(0, Math.min(100, value)));
+    }
+
+    public static Percent fromFraction(double fraction){
+        return new Percent(fraction * 100);
+    }
+
+    public double toFraction(){ return value / 100;}
+
+    protected String symbol(){ return "%";}
+}
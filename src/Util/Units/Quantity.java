@@ -0,0 +1,39 @@
+package Util.Units;
+
+// Base for the unit newtypes. Values must be finite; equality and ordering
+// only hold between quantities of the same unit.
+public abstract class Quantity<Q extends Quantity<Q>> implements Comparable<Q> {
+    protected final double value;
+
+    protected Quantity(double value){
+        if (Double.isNaN(value) || Double.isInfinite(value)){
+            throw new IllegalArgumentException("Not a finite " + getClass().getSimpleName() + " value : " + value);
+        }
+        this.value = value;
+    }
+
+    public double getValue(){ return value;}
+
+    public float floatValue(){ return (float) value;}
+
+    protected abstract String symbol();
+
+    public int compareTo(Q o){
+        return Double.compare(value, o.value);
+    }
+
+    @Override
+    public boolean equals(Object o){
+        return o != null && o.getClass() == getClass() && Double.compare(((Quantity<?>) o).value, value) == 0;
+    }
+
+    @Override
+    public int hashCode(){
+        return 31 * getClass().hashCode() + Double.hashCode(value);
+    }
+
+    @Override
+    public String toString(){
+        return value + " " + symbol();
+    }
+}
@@ -0,0 +1,29 @@
+package Util.Units;
+
+public final class Radians extends Quantity<Radians> {
+    public static final Radians ZERO = new Radians(0);
+
+    private Radians(double value){
+        super(value);
+    }
+
+    public static Radians of(double value){
+        return new Radians(value);
+    }
+
+    public Radians add(Radians o){ return new Radians(value + o.value);}
+
+    public Radians sub(Radians o){ return new Radians(value - o.value);}
+
+    public Radians scale(double factor){ return new Radians(value * factor);}
+
+    public Degrees toDegrees(){ return Degrees.of(Math.toDegrees(value));}
+
+    // Wrapped into [-pi, pi).
+    public Radians normalized(){
+        double r = (value + Math.PI) % (2 * Math.PI);
+        return new Radians((r < 0 ? r + 2 * Math.PI : r) - Math.PI);
+    }
+
+    protected String symbol(){ return "rad";}
+}
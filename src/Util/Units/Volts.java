@@ -0,0 +1,23 @@
+package Util.Units;
+
+public final class Volts extends Quantity<Volts> {
+    public static final Volts ZERO = new Volts(0);
+
+    private Volts(double value){
+        super(value);
+    }
+
+    public static Volts of(double value){
+        return new Volts(value);
+    }
+
+    public Volts add(Volts o){ return new Volts(value + o.value);}
+
+    public Volts sub(Volts o){ return new Volts(value - o.value);}
+
+    public Volts scale(double factor){ return new Volts(value * factor);}
+
+    public double watts(Amps current){ return value * current.value;}
+
+    protected String symbol(){ return "V";}
+}
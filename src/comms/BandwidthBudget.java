@@ -0,0 +1,123 @@
+package comms;
+
+import Util.Rate.TokenBucket;
+
+import java.util.LinkedHashMap;
+import java.util.Map;
+
+// Splits a bytes/sec budget across named consumers by weight. New consumers
+// start with a full bucket. A consumer that has spent its share may borrow
+// from another whose balance is above its reserve, a fraction of its own
+// capacity that is never lent out. Callers keep refused traffic queued; the
+// time it waited is recorded per consumer.
+public class BandwidthBudget {
+    public static final double DEFAULT_LEND_RESERVE = 0.5;
+
+    private final double burstSeconds;
+    private final double lendReserve;
+    private double totalBytesPerSec;
+    private final Map<String, Consumer> consumers = new LinkedHashMap<String, Consumer>();
+
+    private static class Consumer {
+        final double weight;
+        final TokenBucket bucket;
+        long deferredSinceMs = -1;
+        long deferredMs;
+        long borrowedBytes;
+
+        Consumer(double weight, TokenBucket bucket){
+            this.weight = weight;
+            this.bucket = bucket;
+        }
+    }
+
+    public BandwidthBudget(double totalBytesPerSec, double burstSeconds, double lendReserve){
+        if (lendReserve < 0 || lendReserve > 1){
+            throw new IllegalArgumentException("Lend reserve must be within [0, 1] : " + lendReserve);
+        }
+        this.totalBytesPerSec = totalBytesPerSec;
+        this.burstSeconds = burstSeconds;
+        this.lendReserve = lendReserve;
+    }
+
+    public BandwidthBudget(double totalBytesPerSec, double burstSeconds){
+        this(totalBytesPerSec, burstSeconds, DEFAULT_LEND_RESERVE);
+    }
+
+    public void register(String name, double weight, long nowMs){
+        Consumer consumer = new Consumer(weight, new TokenBucket(0, 0, nowMs));
+        consumers.put(name, consumer);
+        rebalance(nowMs);
+        consumer.bucket.fill(nowMs);
+    }
+
+    public boolean isRegistered(String name){
+        return consumers.containsKey(name);
+    }
+
+    public boolean tryConsume(String name, int bytes, long nowMs){
+        Consumer consumer = consumers.get(name);
+        if (consumer == null){
+            throw new IllegalArgumentException("Unknown consumer : " + name);
+        }
+        boolean granted = consumer.bucket.tryConsume(bytes, nowMs);
+        // A frame larger than the whole burst would never fit, so it goes out
+        // once the bucket is full and leaves the consumer in debt.
+        if (!granted && bytes > consumer.bucket.getCapacity()
+                && consumer.bucket.available(nowMs) >= consumer.bucket.getCapacity()){
+            consumer.bucket.take(bytes);
+            granted = true;
+        }
+        if (!granted){
+            for (Consumer lender : consumers.values()){
+                double surplus = lender.bucket.available(nowMs) - lender.bucket.getCapacity() * lendReserve;
+                if (lender != consumer && surplus >= bytes){
+                    lender.bucket.take(bytes);
+                    consumer.borrowedBytes += bytes;
+                    granted = true;
+                    break;
+                }
+            }
+        }
+        if (granted && consumer.deferredSinceMs >= 0){
+            consumer.deferredMs += nowMs - consumer.deferredSinceMs;
+            consumer.deferredSinceMs = -1;
+        } else if (!granted && consumer.deferredSinceMs < 0){
+            consumer.deferredSinceMs = nowMs;
+        }
+        return granted;
+    }
+
+    // Applies to every consumer at once; tokens already earned are kept.
+    public void setTotal(double totalBytesPerSec, long nowMs){
+        this.totalBytesPerSec = totalBytesPerSec;
+        rebalance(nowMs);
+    }
+
+    public double getTotal(){
+        return totalBytesPerSec;
+    }
+
+    public double shareOf(String name){
+        return consumers.get(name).bucket.getRatePerSec();
+    }
+
+    public long getDeferredMs(String name){
+        return consumers.get(name).deferredMs;
+    }
+
+    public long getBorrowedBytes(String name){
+        return consumers.get(name).borrowedBytes;
+    }
+
+    private void rebalance(long nowMs){
+        double weights = 0;
+        for (Consumer consumer : consumers.values()){
+            weights += consumer.weight;
+        }
+        for (Consumer consumer : consumers.values()){
+            double rate = weights == 0 ? 0 : totalBytesPerSec * consumer.weight / weights;
+            consumer.bucket.setRate(rate, rate * burstSeconds, nowMs);
+        }
+    }
+}
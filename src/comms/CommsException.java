@@ -0,0 +1,13 @@
+package comms;
+
+public class CommsException extends Exception {
+    private static final long serialVersionUID = 1L;
+
+    public CommsException(String message){
+        super(message);
+    }
+
+    public CommsException(String message, Throwable cause){
+        super(message, cause);
+    }
+}
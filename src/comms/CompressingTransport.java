@@ -0,0 +1,67 @@
+package comms;
+
+import java.util.Arrays;
+
+// Applies a CompressionPolicy to everything sent over a Transport and undoes
+// it on receive. Frames that do not decompress, or that would not fit the
+// caller's buffer once decompressed, are dropped and counted.
+public class CompressingTransport<A> implements Transport<A> {
+    private final Transport<A> inner;
+    private final CompressionPolicy policy;
+    private final String stream;
+    private final byte[] packet;
+    private long rejected;
+
+    public CompressingTransport(Transport<A> inner, CompressionPolicy policy, String stream){
+        this.inner = inner;
+        this.policy = policy;
+        this.stream = stream;
+        this.packet = new byte[inner.maxPayload()];
+    }
+
+    // Incompressible payloads go out as they are, so only the header is lost.
+    public int maxPayload(){
+        return inner.maxPayload() - CompressionPolicy.HEADER_LEN;
+    }
+
+    public void send(A to, byte[] data) throws CommsException {
+        if (data.length > maxPayload()){
+            throw new CommsException("Payload of " + data.length + " bytes exceeds max " + maxPayload());
+        }
+        inner.send(to, policy.frame(stream, data));
+    }
+
+    public Received<A> pollRecv(byte[] buf) throws CommsException {
+        while (true){
+            Received<A> received = inner.pollRecv(packet);
+            if (received == null){
+                return null;
+            }
+            byte[] payload;
+            try {
+                payload = CompressionPolicy.unframe(Arrays.copyOf(packet, received.getLength()), buf.length);
+            } catch (DecompressException e){
+                rejected++;
+                continue;
+            }
+            System.arraycopy(payload, 0, buf, 0, payload.length);
+            return new Received<A>(received.getFrom(), payload.length);
+        }
+    }
+
+    public long getOversized(){
+        return inner.getOversized();
+    }
+
+    public A localAddr(){
+        return inner.localAddr();
+    }
+
+    public long getRejected(){
+        return rejected;
+    }
+
+    public CompressionPolicy getPolicy(){
+        return policy;
+    }
+}
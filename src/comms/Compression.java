@@ -0,0 +1,202 @@
+package comms;
+
+import java.io.ByteArrayOutputStream;
+import java.util.Arrays;
+
+// The ordinal-independent flag value is what goes into the wire header.
+public enum Compression {
+    NONE(0) {
+        byte[] encode(byte[] data){ return data.clone();}
+
+        byte[] decode(byte[] data, int maxOutput) throws DecompressException {
+            if (data.length > maxOutput){
+                throw new DecompressException("Output exceeds limit of " + maxOutput + " bytes");
+            }
+            return data.clone();
+        }
+    },
+    // [run length 1-255][byte] pairs.
+    RLE(1) {
+        byte[] encode(byte[] data){
+            ByteArrayOutputStream out = new ByteArrayOutputStream();
+            int i = 0;
+            while (i < data.length){
+                int run = 1;
+                while (i + run < data.length && run < 255 && data[i + run] == data[i]){
+                    run++;
+                }
+                out.write(run);
+                out.write(data[i]);
+                i += run;
+            }
+            return out.toByteArray();
+        }
+
+        byte[] decode(byte[] data, int maxOutput) throws DecompressException {
+            if (data.length % 2 != 0){
+                throw new DecompressException("Truncated run");
+            }
+            ByteArrayOutputStream out = new ByteArrayOutputStream();
+            for (int i = 0; i < data.length; i += 2){
+                int run = data[i] & 0xFF;
+                if (run == 0){
+                    throw new DecompressException("Zero length run at offset " + i);
+                }
+                if (out.size() + run > maxOutput){
+                    throw new DecompressException("Output exceeds limit of " + maxOutput + " bytes");
+                }
+                for (int j = 0; j < run; j++){
+                    out.write(data[i + 1]);
+                }
+            }
+            return out.toByteArray();
+        }
+    },
+    // LZSS: a flag byte announces the next 8 tokens, a set bit is a 2 byte
+    // back-reference (12 bit distance, 4 bit length - MIN_MATCH), a clear bit a literal.
+    // Matches are found through hash chains over the first MIN_MATCH bytes,
+    // following at most MAX_CHAIN earlier positions, nearest first.
+    LZ77(2) {
+        byte[] encode(byte[] data){
+            ByteArrayOutputStream out = new ByteArrayOutputStream();
+            byte[] group = new byte[16];
+            int[] head = new int[1 << HASH_BITS];
+            int[] prev = new int[WINDOW];
+            Arrays.fill(head, -1);
+            int pos = 0;
+            while (pos < data.length){
+                int flags = 0;
+                int used = 0;
+                for (int token = 0; token < 8 && pos < data.length; token++){
+                    int bestLen = 0;
+                    int bestDist = 0;
+                    if (pos + MIN_MATCH <= data.length){
+                        int candidate = head[hash(data, pos)];
+                        for (int steps = 0; steps < MAX_CHAIN && candidate >= 0 && pos - candidate <= WINDOW; steps++){
+                            int len = 0;
+                            while (len < MAX_MATCH && pos + len < data.length && data[candidate + len] == data[pos + len]){
+                                len++;
+                            }
+                            if (len > bestLen){
+                                bestLen = len;
+                                bestDist = pos - candidate;
+                                if (len == MAX_MATCH){
+                                    break;
+                                }
+                            }
+                            int next = prev[candidate & (WINDOW - 1)];
+                            if (next >= candidate){
+                                break;
+                            }
+                            candidate = next;
+                        }
+                    }
+                    int advance;
+                    if (bestLen >= MIN_MATCH){
+                        flags |= 1 << token;
+                        group[used++] = (byte) ((bestDist - 1) >> 4);
+                        group[used++] = (byte) (((bestDist - 1) & 0x0F) << 4 | (bestLen - MIN_MATCH));
+                        advance = bestLen;
+                    } else {
+                        group[used++] = data[pos];
+                        advance = 1;
+                    }
+                    for (int end = pos + advance; pos < end; pos++){
+                        if (pos + MIN_MATCH <= data.length){
+                            int h = hash(data, pos);
+                            prev[pos & (WINDOW - 1)] = head[h];
+                            head[h] = pos;
+                        }
+                    }
+                }
+                out.write(flags);
+                out.write(group, 0, used);
+            }
+            return out.toByteArray();
+        }
+
+        byte[] decode(byte[] data, int maxOutput) throws DecompressException {
+            byte[] out = new byte[Math.min(maxOutput, data.length * 2 + 16)];
+            int size = 0;
+            int pos = 0;
+            while (pos < data.length){
+                int flags = data[pos++] & 0xFF;
+                for (int token = 0; token < 8 && pos < data.length; token++){
+                    int dist = 0;
+                    int len = 1;
+                    if ((flags & (1 << token)) != 0){
+                        if (pos + 1 >= data.length){
+                            throw new DecompressException("Truncated back-reference at offset " + pos);
+                        }
+                        dist = ((data[pos] & 0xFF) << 4 | (data[pos + 1] & 0xFF) >> 4) + 1;
+                        len = (data[pos + 1] & 0x0F) + MIN_MATCH;
+                        if (dist > size){
+                            throw new DecompressException("Back-reference before start of output");
+                        }
+                    }
+                    if (size + len > maxOutput){
+                        throw new DecompressException("Output exceeds limit of " + maxOutput + " bytes");
+                    }
+                    if (size + len > out.length){
+                        out = Arrays.copyOf(out, Math.min(maxOutput, Math.max(out.length * 2, size + len)));
+                    }
+                    if (dist == 0){
+                        out[size++] = data[pos++];
+                    } else {
+                        // Byte-by-byte so overlapping references repeat correctly.
+                        for (int i = 0; i < len; i++, size++){
+                            out[size] = out[size - dist];
+                        }
+                        pos += 2;
+                    }
+                }
+            }
+            return Arrays.copyOf(out, size);
+        }
+    };
+
+    static final int WINDOW = 4096;
+    static final int MIN_MATCH = 3;
+    static final int MAX_MATCH = 15 + MIN_MATCH;
+    static final int HASH_BITS = 12;
+    static final int MAX_CHAIN = 64;
+
+    private final int flag;
+
+    Compression(int flag){
+        this.flag = flag;
+    }
+
+    abstract byte[] encode(byte[] data);
+
+    abstract byte[] decode(byte[] data, int maxOutput) throws DecompressException;
+
+    public int getFlag(){
+        return flag;
+    }
+
+    public static Compression fromFlag(int flag){
+        for (Compression algo : values()){
+            if (algo.flag == flag){
+                return algo;
+            }
+        }
+        throw new IllegalArgumentException("Unknown compression flag : " + flag);
+    }
+
+    public static byte[] compress(byte[] data, Compression algo){
+        return algo.encode(data);
+    }
+
+    public static byte[] decompress(byte[] data, Compression algo, int maxOutput) throws DecompressException {
+        if (maxOutput < 0){
+            throw new IllegalArgumentException("maxOutput must not be negative : " + maxOutput);
+        }
+        return algo.decode(data, maxOutput);
+    }
+
+    private static int hash(byte[] data, int pos){
+        int key = (data[pos] & 0xFF) << 16 | (data[pos + 1] & 0xFF) << 8 | (data[pos + 2] & 0xFF);
+        return (key * 0x9E3779B1) >>> (32 - HASH_BITS);
+    }
+}
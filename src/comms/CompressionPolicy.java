@@ -0,0 +1,113 @@
+package comms;
+
+import java.util.Arrays;
+import java.util.HashMap;
+import java.util.Map;
+
+// TX-side policy: only compress payloads above the threshold, and only keep the
+// result when it is actually smaller than the original.
+//
+// On the wire the result is preceded by a one byte header carrying the
+// algorithm's flag in its low two bits; the other bits are reserved and must
+// be zero.
+public class CompressionPolicy {
+    public static final int HEADER_LEN = 1;
+    private static final int ALGO_MASK = 0x03;
+
+    private final Compression algo;
+    private final int threshold;
+    private final Map<String, Stats> stats = new HashMap<String, Stats>();
+
+    public static class Encoded {
+        private final Compression algo;
+        private final byte[] data;
+
+        Encoded(Compression algo, byte[] data){
+            this.algo = algo;
+            this.data = data;
+        }
+
+        public Compression getAlgo(){ return algo;}
+
+        public byte[] getData(){ return data;}
+    }
+
+    public static class Stats {
+        private long bytesIn;
+        private long bytesOut;
+        private long compressed;
+        private long skipped;
+
+        public long getBytesIn(){ return bytesIn;}
+
+        public long getBytesOut(){ return bytesOut;}
+
+        public long getCompressed(){ return compressed;}
+
+        public long getSkipped(){ return skipped;}
+
+        public double getRatio(){
+            return bytesIn == 0 ? 1.0 : (double) bytesOut / bytesIn;
+        }
+    }
+
+    public CompressionPolicy(Compression algo, int threshold){
+        this.algo = algo;
+        this.threshold = threshold;
+    }
+
+    public Encoded encode(String stream, byte[] payload){
+        Encoded encoded = new Encoded(Compression.NONE, payload);
+        if (algo != Compression.NONE && payload.length > threshold){
+            byte[] packed = Compression.compress(payload, algo);
+            if (packed.length < payload.length){
+                encoded = new Encoded(algo, packed);
+            }
+        }
+        Stats s = getStats(stream);
+        s.bytesIn += payload.length;
+        s.bytesOut += encoded.data.length;
+        if (encoded.algo == Compression.NONE){
+            s.skipped++;
+        } else {
+            s.compressed++;
+        }
+        return encoded;
+    }
+
+    // encode() plus the wire header.
+    public byte[] frame(String stream, byte[] payload){
+        Encoded encoded = encode(stream, payload);
+        byte[] out = new byte[HEADER_LEN + encoded.data.length];
+        out[0] = (byte) encoded.algo.getFlag();
+        System.arraycopy(encoded.data, 0, out, HEADER_LEN, encoded.data.length);
+        return out;
+    }
+
+    // Reads the header and decompresses with whichever algorithm it names.
+    public static byte[] unframe(byte[] frame, int maxOutput) throws DecompressException {
+        if (frame.length < HEADER_LEN){
+            throw new DecompressException("Missing compression header");
+        }
+        int header = frame[0] & 0xFF;
+        if ((header & ~ALGO_MASK) != 0){
+            throw new DecompressException("Reserved header bits set : 0x" + Integer.toHexString(header));
+        }
+        Compression algo;
+        try {
+            algo = Compression.fromFlag(header);
+        } catch (IllegalArgumentException e){
+            throw new DecompressException("Unknown compression flag : " + header);
+        }
+        return Compression.decompress(Arrays.copyOfRange(frame, HEADER_LEN, frame.length), algo, maxOutput);
+    }
+
+    public Stats getStats(String stream){
+        Stats s = stats.get(stream);
+        if (s == null){
+            s = new Stats();
+            stats.put(stream, s);
+        }
+        return s;
+    }
+}
@@ -0,0 +1,9 @@
+package comms;
+
+public class DecompressException extends Exception {
+    private static final long serialVersionUID = 1L;
+
+    public DecompressException(String message){
+        super(message);
+    }
+}
@@ -0,0 +1,64 @@
+package comms;
+
+import Util.Crc;
+
+import java.nio.ByteBuffer;
+import java.util.ArrayList;
+import java.util.List;
+
+// Splits payloads larger than the transport MTU. Every fragment carries
+// (message id, index, total, whole-message CRC32) ahead of its data.
+public class Fragmenter {
+    public static final int HEADER_LEN = 12;
+    public static final int MAX_FRAGMENTS = 0xFFFF;
+
+    private final int mtu;
+
+    public Fragmenter(int mtu){
+        if (mtu <= HEADER_LEN){
+            throw new IllegalArgumentException("MTU must exceed the fragment header : " + mtu);
+        }
+        this.mtu = mtu;
+    }
+
+    public int fragmentCount(byte[] payload){
+        int chunk = mtu - HEADER_LEN;
+        return Math.max(1, (payload.length + chunk - 1) / chunk);
+    }
+
+    public List<byte[]> split(int messageId, byte[] payload){
+        int total = fragmentCount(payload);
+        if (total > MAX_FRAGMENTS){
+            throw new IllegalArgumentException("Payload needs " + total + " fragments");
+        }
+        long crc = crc32(payload);
+        List<byte[]> fragments = new ArrayList<byte[]>();
+        for (int i = 0; i < total; i++){
+            fragments.add(fragment(messageId, payload, i, total, crc));
+        }
+        return fragments;
+    }
+
+    // Rebuilds a single fragment, used to answer a selective retransmit request.
+    public byte[] fragment(int messageId, byte[] payload, int index){
+        int total = fragmentCount(payload);
+        if (index < 0 || index >= total){
+            throw new IndexOutOfBoundsException("Fragment " + index + " of " + total);
+        }
+        return fragment(messageId, payload, index, total, crc32(payload));
+    }
+
+    private byte[] fragment(int messageId, byte[] payload, int index, int total, long crc){
+        int chunk = mtu - HEADER_LEN;
+        int start = index * chunk;
+        int len = Math.min(chunk, payload.length - start);
+        ByteBuffer b = ByteBuffer.allocate(HEADER_LEN + len);
+        b.putInt(messageId).putShort((short) index).putShort((short) total).putInt((int) crc);
+        b.put(payload, start, len);
+        return b.array();
+    }
+
+    static long crc32(byte[] data){
+        return Crc.Crc32.compute(data);
+    }
+}
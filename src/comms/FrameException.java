@@ -0,0 +1,9 @@
+package comms;
+
+public class FrameException extends Exception {
+    private static final long serialVersionUID = 1L;
+
+    public FrameException(String message){
+        super(message);
+    }
+}
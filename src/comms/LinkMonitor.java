@@ -0,0 +1,236 @@
+package comms;
+
+import Util.Enums.LinkGrade;
+
+import java.util.ArrayDeque;
+import java.util.ArrayList;
+import java.util.Deque;
+import java.util.HashMap;
+import java.util.Iterator;
+import java.util.LinkedHashMap;
+import java.util.List;
+import java.util.Map;
+import java.util.function.Consumer;
+
+// Link quality for one peer, fed by the reliable channel's send/ack/retransmit/
+// receive events. RTT smoothing follows RFC 6298 and skips samples from
+// retransmitted packets (Karn's rule). A packet or probe still unacked one RTO
+// after its last transmission counts as lost; a late ack for it is ignored.
+public class LinkMonitor {
+    public static final double RTT_ALPHA = 0.125;
+    public static final double RTT_BETA = 0.25;
+    public static final long INITIAL_RTO_MS = 1000;
+    public static final long MIN_RTO_MS = 200;
+
+    private final int lossWindow;
+    private final long throughputWindowMs;
+    private final long probeIntervalMs;
+    private final long lostTimeoutMs;
+
+    private double rttSmoothedMs = -1;
+    private double rttVarMs;
+    private final Map<Long, Long> inFlight = new LinkedHashMap<Long, Long>();
+    private final Map<Long, Boolean> retransmitted = new HashMap<Long, Boolean>();
+    private final Deque<Boolean> outcomes = new ArrayDeque<Boolean>();
+    private final Deque<long[]> traffic = new ArrayDeque<long[]>();
+    private long lastHeardMs = -1;
+    private long lastActivityMs;
+    private long nextProbeId;
+    private LinkGrade grade = LinkGrade.GOOD;
+    private final List<Consumer<LinkReport>> listeners = new ArrayList<Consumer<LinkReport>>();
+
+    public LinkMonitor(int lossWindow, long throughputWindowMs, long probeIntervalMs, long lostTimeoutMs){
+        this.lossWindow = lossWindow;
+        this.throughputWindowMs = throughputWindowMs;
+        this.probeIntervalMs = probeIntervalMs;
+        this.lostTimeoutMs = lostTimeoutMs;
+    }
+
+    public LinkMonitor(){
+        this(32, 1000, 1000, 5000);
+    }
+
+    public void onSend(long seq, int bytes, long nowMs){
+        inFlight.put(seq, nowMs);
+        lastActivityMs = nowMs;
+    }
+
+    public void onRetransmit(long seq, long nowMs){
+        lastActivityMs = nowMs;
+        if (inFlight.containsKey(seq)){
+            inFlight.put(seq, nowMs);
+            retransmitted.put(seq, true);
+        }
+    }
+
+    public void onAck(long seq, int bytes, long nowMs){
+        Long sentAt = inFlight.remove(seq);
+        if (sentAt == null){
+            // Duplicate, late or unknown ack: the peer is alive, but it says
+            // nothing about delivery.
+            heard(bytes, nowMs);
+            return;
+        }
+        boolean wasRetransmitted = retransmitted.remove(seq) != null;
+        if (!wasRetransmitted){
+            sampleRtt(nowMs - sentAt);
+        }
+        recordOutcome(!wasRetransmitted);
+        heard(bytes, nowMs);
+    }
+
+    public void onReceive(int bytes, long nowMs){
+        heard(bytes, nowMs);
+    }
+
+    // A probe is only worth sending when nothing else has exercised the link
+    // recently; acks on real traffic already give us RTT samples.
+    public boolean shouldProbe(long nowMs){
+        return nowMs - lastActivityMs >= probeIntervalMs;
+    }
+
+    public long sendProbe(long nowMs){
+        long id = -(++nextProbeId);
+        onSend(id, 0, nowMs);
+        return id;
+    }
+
+    public void onPong(long probeId, long nowMs){
+        onAck(probeId, 0, nowMs);
+    }
+
+    public void addListener(Consumer<LinkReport> listener){
+        listeners.add(listener);
+    }
+
+    // Retransmission timeout per RFC 6298, capped at lostTimeoutMs.
+    public long rtoMs(){
+        if (rttSmoothedMs < 0){
+            return Math.min(INITIAL_RTO_MS, lostTimeoutMs);
+        }
+        long rto = (long) Math.ceil(rttSmoothedMs + 4 * rttVarMs);
+        return Math.min(Math.max(MIN_RTO_MS, rto), lostTimeoutMs);
+    }
+
+    // Counts every packet unacked for longer than the RTO as lost and forgets
+    // it. report() calls this, so only callers that skip report need it.
+    public int expire(long nowMs){
+        long rto = rtoMs();
+        int lost = 0;
+        Iterator<Map.Entry<Long, Long>> it = inFlight.entrySet().iterator();
+        while (it.hasNext()){
+            Map.Entry<Long, Long> entry = it.next();
+            if (nowMs - entry.getValue() > rto){
+                retransmitted.remove(entry.getKey());
+                it.remove();
+                recordOutcome(false);
+                lost++;
+            }
+        }
+        return lost;
+    }
+
+    public int getInFlight(){
+        return inFlight.size();
+    }
+
+    public int getTrafficSamples(){
+        return traffic.size();
+    }
+
+    public LinkReport report(long nowMs){
+        expire(nowMs);
+        trimTraffic(nowMs);
+        long bytes = 0;
+        for (long[] entry : traffic){
+            bytes += entry[1];
+        }
+        double throughput = bytes * 8 * 1000.0 / throughputWindowMs;
+        double loss = lossRate();
+        grade = classify(nowMs, loss);
+        LinkReport report = new LinkReport(Math.max(rttSmoothedMs, 0), rttVarMs, loss, throughput, lastHeardMs, grade);
+        for (Consumer<LinkReport> listener : listeners){
+            listener.accept(report);
+        }
+        return report;
+    }
+
+    public LinkGrade getGrade(){
+        return grade;
+    }
+
+    public long getLastHeardMs(){
+        return lastHeardMs;
+    }
+
+    private void sampleRtt(long rttMs){
+        if (rttSmoothedMs < 0){
+            rttSmoothedMs = rttMs;
+            rttVarMs = rttMs / 2.0;
+        } else {
+            rttVarMs = (1 - RTT_BETA) * rttVarMs + RTT_BETA * Math.abs(rttSmoothedMs - rttMs);
+            rttSmoothedMs = (1 - RTT_ALPHA) * rttSmoothedMs + RTT_ALPHA * rttMs;
+        }
+    }
+
+    private void recordOutcome(boolean delivered){
+        outcomes.addLast(delivered);
+        while (outcomes.size() > lossWindow){
+            outcomes.pollFirst();
+        }
+    }
+
+    private void heard(int bytes, long nowMs){
+        lastHeardMs = nowMs;
+        lastActivityMs = nowMs;
+        traffic.addLast(new long[]{nowMs, bytes});
+        // Trim here too: a peer that is heard a lot but never reported on
+        // must not grow the deque without bound.
+        trimTraffic(nowMs);
+    }
+
+    private void trimTraffic(long nowMs){
+        while (!traffic.isEmpty() && nowMs - traffic.peekFirst()[0] > throughputWindowMs){
+            traffic.pollFirst();
+        }
+    }
+
+    private double lossRate(){
+        if (outcomes.isEmpty()){
+            return 0;
+        }
+        int lost = 0;
+        for (Boolean delivered : outcomes){
+            if (!delivered){
+                lost++;
+            }
+        }
+        return (double) lost / outcomes.size();
+    }
+
+    // Degrading takes effect immediately, recovering needs the loss rate to
+    // clear a lower threshold so the grade does not flap around a boundary.
+    private LinkGrade classify(long nowMs, double loss){
+        if (lastHeardMs < 0 || nowMs - lastHeardMs > lostTimeoutMs){
+            return LinkGrade.LOST;
+        }
+        LinkGrade target;
+        if (loss >= 0.3){
+            target = LinkGrade.POOR;
+        } else if (loss >= 0.1){
+            target = LinkGrade.DEGRADED;
+        } else {
+            target = LinkGrade.GOOD;
+        }
+        if (target.ordinal() >= grade.ordinal() || grade == LinkGrade.LOST){
+            return target;
+        }
+        if (grade == LinkGrade.POOR && loss >= 0.2){
+            return LinkGrade.POOR;
+        }
+        if (grade == LinkGrade.DEGRADED && loss >= 0.05){
+            return LinkGrade.DEGRADED;
+        }
+        return target;
+    }
+}
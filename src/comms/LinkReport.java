@@ -0,0 +1,34 @@
+package comms;
+
+import Util.Enums.LinkGrade;
+
+public class LinkReport {
+    private final double rttSmoothedMs;
+    private final double rttVarMs;
+    private final double lossRateWindow;
+    private final double throughputBps;
+    private final long lastHeardMs;
+    private final LinkGrade grade;
+
+    LinkReport(double rttSmoothedMs, double rttVarMs, double lossRateWindow, double throughputBps,
+               long lastHeardMs, LinkGrade grade){
+        this.rttSmoothedMs = rttSmoothedMs;
+        this.rttVarMs = rttVarMs;
+        this.lossRateWindow = lossRateWindow;
+        this.throughputBps = throughputBps;
+        this.lastHeardMs = lastHeardMs;
+        this.grade = grade;
+    }
+
+    public double getRttSmoothedMs(){ return rttSmoothedMs;}
+
+    public double getRttVarMs(){ return rttVarMs;}
+
+    public double getLossRateWindow(){ return lossRateWindow;}
+
+    public double getThroughputBps(){ return throughputBps;}
+
+    public long getLastHeardMs(){ return lastHeardMs;}
+
+    public LinkGrade getGrade(){ return grade;}
+}
@@ -0,0 +1,332 @@
+package comms;
+
+import Util.Crc;
+
+import java.nio.ByteBuffer;
+import java.nio.ByteOrder;
+import java.util.ArrayList;
+import java.util.Arrays;
+import java.util.List;
+
+public final class Mavlink {
+    public static final int STX = 0xFD;
+    public static final int HEADER_LEN = 10;
+    public static final int CHECKSUM_LEN = 2;
+    public static final int SIGNATURE_LEN = 13;
+    public static final int INCOMPAT_FLAG_SIGNED = 0x01;
+
+    public static final int MAV_CMD_NAV_RETURN_TO_LAUNCH = 20;
+    public static final int MAV_CMD_COMPONENT_ARM_DISARM = 400;
+    public static final int MAV_RESULT_ACCEPTED = 0;
+    public static final int MAV_RESULT_UNSUPPORTED = 3;
+    public static final int MAV_TYPE_QUADROTOR = 2;
+    public static final int MAV_AUTOPILOT_GENERIC = 0;
+    public static final int MAV_MODE_FLAG_SAFETY_ARMED = 0x80;
+    public static final int MAV_STATE_STANDBY = 3;
+    public static final int MAV_STATE_ACTIVE = 4;
+
+    private Mavlink(){}
+
+    // CRC_EXTRA seeds from the common message set, -1 for ids we do not know.
+    public static int crcExtra(int msgId){
+        switch (msgId){
+            case Heartbeat.MSG_ID: return 50;
+            case SysStatus.MSG_ID: return 124;
+            case GlobalPositionInt.MSG_ID: return 104;
+            case CommandLong.MSG_ID: return 152;
+            case CommandAck.MSG_ID: return 143;
+            default: return -1;
+        }
+    }
+
+    public static byte[] encode(Frame frame){
+        int extra = crcExtra(frame.msgId);
+        if (extra < 0){
+            throw new IllegalArgumentException("Unknown message id : " + frame.msgId);
+        }
+        // MAVLink 2 strips trailing zero bytes, but never the first one.
+        int len = frame.payload.length;
+        while (len > 1 && frame.payload[len - 1] == 0){
+            len--;
+        }
+        byte[] out = new byte[HEADER_LEN + len + CHECKSUM_LEN];
+        out[0] = (byte) STX;
+        out[1] = (byte) len;
+        out[2] = 0;
+        out[3] = 0;
+        out[4] = (byte) frame.seq;
+        out[5] = (byte) frame.sysId;
+        out[6] = (byte) frame.compId;
+        out[7] = (byte) frame.msgId;
+        out[8] = (byte) (frame.msgId >> 8);
+        out[9] = (byte) (frame.msgId >> 16);
+        System.arraycopy(frame.payload, 0, out, HEADER_LEN, len);
+        int crc = new Crc.Crc16Mcrf4xx().update(out, 1, HEADER_LEN - 1 + len).update(extra).finish();
+        out[HEADER_LEN + len] = (byte) crc;
+        out[HEADER_LEN + len + 1] = (byte) (crc >> 8);
+        return out;
+    }
+
+    public static byte[] encode(int seq, int sysId, int compId, Message message){
+        return encode(new Frame(seq, sysId, compId, message.msgId(), message.payload()));
+    }
+
+    private static ByteBuffer writer(int len){
+        return ByteBuffer.allocate(len).order(ByteOrder.LITTLE_ENDIAN);
+    }
+
+    // Received payloads may be truncated, so pad them back to the full length.
+    private static ByteBuffer reader(byte[] payload, int len){
+        return ByteBuffer.wrap(Arrays.copyOf(payload, Math.max(len, payload.length))).order(ByteOrder.LITTLE_ENDIAN);
+    }
+
+    public static class Frame {
+        public final int seq;
+        public final int sysId;
+        public final int compId;
+        public final int msgId;
+        public final byte[] payload;
+
+        public Frame(int seq, int sysId, int compId, int msgId, byte[] payload){
+            this.seq = seq & 0xFF;
+            this.sysId = sysId & 0xFF;
+            this.compId = compId & 0xFF;
+            this.msgId = msgId & 0xFFFFFF;
+            this.payload = payload;
+        }
+    }
+
+    public interface Message {
+        int msgId();
+        byte[] payload();
+    }
+
+    public static class Heartbeat implements Message {
+        public static final int MSG_ID = 0;
+        public static final int LEN = 9;
+        public long customMode;
+        public int type;
+        public int autopilot;
+        public int baseMode;
+        public int systemStatus;
+        public int mavlinkVersion = 3;
+
+        public int msgId(){ return MSG_ID;}
+
+        public byte[] payload(){
+            ByteBuffer b = writer(LEN);
+            b.putInt((int) customMode);
+            b.put((byte) type).put((byte) autopilot).put((byte) baseMode);
+            b.put((byte) systemStatus).put((byte) mavlinkVersion);
+            return b.array();
+        }
+
+        public static Heartbeat decode(byte[] payload){
+            ByteBuffer b = reader(payload, LEN);
+            Heartbeat m = new Heartbeat();
+            m.customMode = b.getInt() & 0xFFFFFFFFL;
+            m.type = b.get() & 0xFF;
+            m.autopilot = b.get() & 0xFF;
+            m.baseMode = b.get() & 0xFF;
+            m.systemStatus = b.get() & 0xFF;
+            m.mavlinkVersion = b.get() & 0xFF;
+            return m;
+        }
+    }
+
+    public static class SysStatus implements Message {
+        public static final int MSG_ID = 1;
+        public static final int LEN = 31;
+        public long sensorsPresent;
+        public long sensorsEnabled;
+        public long sensorsHealth;
+        public int load;
+        public int voltageBattery;
+        public short currentBattery;
+        public int dropRateComm;
+        public int errorsComm;
+        public final int[] errorsCount = new int[4];
+        public byte batteryRemaining;
+
+        public int msgId(){ return MSG_ID;}
+
+        public byte[] payload(){
+            ByteBuffer b = writer(LEN);
+            b.putInt((int) sensorsPresent).putInt((int) sensorsEnabled).putInt((int) sensorsHealth);
+            b.putShort((short) load).putShort((short) voltageBattery).putShort(currentBattery);
+            b.putShort((short) dropRateComm).putShort((short) errorsComm);
+            for (int count : errorsCount){
+                b.putShort((short) count);
+            }
+            b.put(batteryRemaining);
+            return b.array();
+        }
+
+        public static SysStatus decode(byte[] payload){
+            ByteBuffer b = reader(payload, LEN);
+            SysStatus m = new SysStatus();
+            m.sensorsPresent = b.getInt() & 0xFFFFFFFFL;
+            m.sensorsEnabled = b.getInt() & 0xFFFFFFFFL;
+            m.sensorsHealth = b.getInt() & 0xFFFFFFFFL;
+            m.load = b.getShort() & 0xFFFF;
+            m.voltageBattery = b.getShort() & 0xFFFF;
+            m.currentBattery = b.getShort();
+            m.dropRateComm = b.getShort() & 0xFFFF;
+            m.errorsComm = b.getShort() & 0xFFFF;
+            for (int i = 0; i < m.errorsCount.length; i++){
+                m.errorsCount[i] = b.getShort() & 0xFFFF;
+            }
+            m.batteryRemaining = b.get();
+            return m;
+        }
+    }
+
+    public static class GlobalPositionInt implements Message {
+        public static final int MSG_ID = 33;
+        public static final int LEN = 28;
+        public long timeBootMs;
+        public int lat;
+        public int lon;
+        public int alt;
+        public int relativeAlt;
+        public short vx;
+        public short vy;
+        public short vz;
+        public int hdg;
+
+        public int msgId(){ return MSG_ID;}
+
+        public byte[] payload(){
+            ByteBuffer b = writer(LEN);
+            b.putInt((int) timeBootMs).putInt(lat).putInt(lon).putInt(alt).putInt(relativeAlt);
+            b.putShort(vx).putShort(vy).putShort(vz).putShort((short) hdg);
+            return b.array();
+        }
+
+        public static GlobalPositionInt decode(byte[] payload){
+            ByteBuffer b = reader(payload, LEN);
+            GlobalPositionInt m = new GlobalPositionInt();
+            m.timeBootMs = b.getInt() & 0xFFFFFFFFL;
+            m.lat = b.getInt();
+            m.lon = b.getInt();
+            m.alt = b.getInt();
+            m.relativeAlt = b.getInt();
+            m.vx = b.getShort();
+            m.vy = b.getShort();
+            m.vz = b.getShort();
+            m.hdg = b.getShort() & 0xFFFF;
+            return m;
+        }
+    }
+
+    public static class CommandLong implements Message {
+        public static final int MSG_ID = 76;
+        public static final int LEN = 33;
+        public final float[] params = new float[7];
+        public int command;
+        public int targetSystem;
+        public int targetComponent;
+        public int confirmation;
+
+        public int msgId(){ return MSG_ID;}
+
+        public byte[] payload(){
+            ByteBuffer b = writer(LEN);
+            for (float param : params){
+                b.putFloat(param);
+            }
+            b.putShort((short) command);
+            b.put((byte) targetSystem).put((byte) targetComponent).put((byte) confirmation);
+            return b.array();
+        }
+
+        public static CommandLong decode(byte[] payload){
+            ByteBuffer b = reader(payload, LEN);
+            CommandLong m = new CommandLong();
+            for (int i = 0; i < m.params.length; i++){
+                m.params[i] = b.getFloat();
+            }
+            m.command = b.getShort() & 0xFFFF;
+            m.targetSystem = b.get() & 0xFF;
+            m.targetComponent = b.get() & 0xFF;
+            m.confirmation = b.get() & 0xFF;
+            return m;
+        }
+    }
+
+    public static class CommandAck implements Message {
+        public static final int MSG_ID = 77;
+        public static final int LEN = 3;
+        public int command;
+        public int result;
+
+        public int msgId(){ return MSG_ID;}
+
+        public byte[] payload(){
+            ByteBuffer b = writer(LEN);
+            b.putShort((short) command).put((byte) result);
+            return b.array();
+        }
+
+        public static CommandAck decode(byte[] payload){
+            ByteBuffer b = reader(payload, LEN);
+            CommandAck m = new CommandAck();
+            m.command = b.getShort() & 0xFFFF;
+            m.result = b.get() & 0xFF;
+            return m;
+        }
+    }
+
+    // Stream decoder: frames with an unknown id are skipped by their length field,
+    // frames with a bad checksum drop the start byte and resync on the next STX.
+    public static class Parser {
+        private byte[] buffer = new byte[0];
+        private long crcErrors;
+        private long unknownSkipped;
+
+        public List<Frame> feed(byte[] data){
+            byte[] joined = Arrays.copyOf(buffer, buffer.length + data.length);
+            System.arraycopy(data, 0, joined, buffer.length, data.length);
+            List<Frame> frames = new ArrayList<Frame>();
+            int pos = 0;
+            while (pos < joined.length){
+                if ((joined[pos] & 0xFF) != STX){
+                    pos++;
+                    continue;
+                }
+                if (joined.length - pos < HEADER_LEN){
+                    break;
+                }
+                int len = joined[pos + 1] & 0xFF;
+                boolean signed = (joined[pos + 2] & INCOMPAT_FLAG_SIGNED) != 0;
+                int total = HEADER_LEN + len + CHECKSUM_LEN + (signed ? SIGNATURE_LEN : 0);
+                if (joined.length - pos < total){
+                    break;
+                }
+                int msgId = (joined[pos + 7] & 0xFF) | (joined[pos + 8] & 0xFF) << 8 | (joined[pos + 9] & 0xFF) << 16;
+                int extra = crcExtra(msgId);
+                if (extra < 0){
+                    unknownSkipped++;
+                    pos += total;
+                    continue;
+                }
+                int crc = new Crc.Crc16Mcrf4xx().update(joined, pos + 1, HEADER_LEN - 1 + len).update(extra).finish();
+                int got = (joined[pos + HEADER_LEN + len] & 0xFF) | (joined[pos + HEADER_LEN + len + 1] & 0xFF) << 8;
+                if (crc != got){
+                    crcErrors++;
+                    pos++;
+                    continue;
+                }
+                byte[] payload = Arrays.copyOfRange(joined, pos + HEADER_LEN, pos + HEADER_LEN + len);
+                frames.add(new Frame(joined[pos + 4], joined[pos + 5], joined[pos + 6], msgId, payload));
+                pos += total;
+            }
+            buffer = Arrays.copyOfRange(joined, pos, joined.length);
+            return frames;
+        }
+
+        public long getCrcErrors(){ return crcErrors;}
+
+        public long getUnknownSkipped(){ return unknownSkipped;}
+    }
+}
@@ -0,0 +1,124 @@
+package comms;
+
+import Util.Enums.DroneCommand;
+
+import java.util.Arrays;
+import java.util.function.Consumer;
+
+// Minimal MAVLink presence on a Transport: a HEARTBEAT to the ground station
+// once a second, and COMMAND_LONG requests addressed to us turned into
+// DroneCommands and answered with COMMAND_ACK. Call poll() from the main loop.
+public class MavlinkEndpoint<A> {
+    public static final long HEARTBEAT_INTERVAL_MS = 1000;
+    // Receive failures tolerated in one poll before leaving the rest of the
+    // queue to the next one, so a dead link cannot spin the main loop.
+    public static final int MAX_RECV_ERRORS_PER_POLL = 8;
+
+    private final Transport<A> transport;
+    private final A groundStation;
+    private final int sysId;
+    private final int compId;
+    private final Consumer<DroneCommand> commands;
+    private final Mavlink.Parser parser = new Mavlink.Parser();
+    private final byte[] buf;
+    private int seq;
+    private long lastHeartbeatMs = -1;
+    private boolean armed;
+    private long unsupported;
+    private long recvErrors;
+
+    public MavlinkEndpoint(Transport<A> transport, A groundStation, int sysId, int compId, Consumer<DroneCommand> commands){
+        this.transport = transport;
+        this.groundStation = groundStation;
+        this.sysId = sysId;
+        this.compId = compId;
+        this.commands = commands;
+        this.buf = new byte[transport.maxPayload()];
+    }
+
+    public void poll(long nowMs) throws CommsException {
+        if (lastHeartbeatMs < 0 || nowMs - lastHeartbeatMs >= HEARTBEAT_INTERVAL_MS){
+            sendHeartbeat();
+            lastHeartbeatMs = nowMs;
+        }
+        int errors = 0;
+        while (true){
+            Transport.Received<A> received;
+            try {
+                received = transport.pollRecv(buf);
+            } catch (CommsException e){
+                recvErrors++;
+                if (++errors >= MAX_RECV_ERRORS_PER_POLL){
+                    return;
+                }
+                continue;
+            }
+            if (received == null){
+                return;
+            }
+            for (Mavlink.Frame frame : parser.feed(Arrays.copyOf(buf, received.getLength()))){
+                if (frame.msgId == Mavlink.CommandLong.MSG_ID){
+                    handleCommand(Mavlink.CommandLong.decode(frame.payload), received.getFrom());
+                }
+            }
+        }
+    }
+
+    // Maps a COMMAND_LONG to a DroneCommand, or null when we do not support it.
+    public static DroneCommand translate(Mavlink.CommandLong command){
+        switch (command.command){
+            case Mavlink.MAV_CMD_COMPONENT_ARM_DISARM:
+                return command.params[0] >= 0.5f ? DroneCommand.ARM : DroneCommand.DISARM;
+            case Mavlink.MAV_CMD_NAV_RETURN_TO_LAUNCH:
+                return DroneCommand.RETURN_TO_LAUNCH;
+            default:
+                return null;
+        }
+    }
+
+    private void handleCommand(Mavlink.CommandLong command, A from) throws CommsException {
+        // Target system 0 is a broadcast.
+        if (command.targetSystem != 0 && command.targetSystem != sysId){
+            return;
+        }
+        DroneCommand translated = translate(command);
+        Mavlink.CommandAck ack = new Mavlink.CommandAck();
+        ack.command = command.command;
+        if (translated == null){
+            unsupported++;
+            ack.result = Mavlink.MAV_RESULT_UNSUPPORTED;
+        } else {
+            commands.accept(translated);
+            ack.result = Mavlink.MAV_RESULT_ACCEPTED;
+        }
+        send(from, ack);
+    }
+
+    private void sendHeartbeat() throws CommsException {
+        Mavlink.Heartbeat heartbeat = new Mavlink.Heartbeat();
+        heartbeat.type = Mavlink.MAV_TYPE_QUADROTOR;
+        heartbeat.autopilot = Mavlink.MAV_AUTOPILOT_GENERIC;
+        heartbeat.baseMode = armed ? Mavlink.MAV_MODE_FLAG_SAFETY_ARMED : 0;
+        heartbeat.systemStatus = armed ? Mavlink.MAV_STATE_ACTIVE : Mavlink.MAV_STATE_STANDBY;
+        send(groundStation, heartbeat);
+    }
+
+    private void send(A to, Mavlink.Message message) throws CommsException {
+        transport.send(to, Mavlink.encode(seq, sysId, compId, message));
+        seq = (seq + 1) & 0xFF;
+    }
+
+    // Reported in the heartbeat; the owner sets it once a command takes effect.
+    public void setArmed(boolean val){
+        this.armed = val;
+    }
+
+    public boolean isArmed(){ return armed;}
+
+    public long getUnsupported(){ return unsupported;}
+
+    public long getRecvErrors(){ return recvErrors;}
+
+    // Frames the transport dropped for not fitting the receive buffer.
+    public long getOversized(){ return transport.getOversized();}
+}
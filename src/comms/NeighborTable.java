@@ -0,0 +1,77 @@
+package comms;
+
+import java.util.ArrayList;
+import java.util.Collection;
+import java.util.Iterator;
+import java.util.LinkedHashMap;
+import java.util.List;
+import java.util.Map;
+
+public class NeighborTable {
+    private final Map<Long, Neighbor> neighbors = new LinkedHashMap<Long, Neighbor>();
+
+    public static class Neighbor {
+        private final long address;
+        private int rssi;
+        private long lastHeardMs;
+
+        Neighbor(long address, int rssi, long lastHeardMs){
+            this.address = address;
+            this.rssi = rssi;
+            this.lastHeardMs = lastHeardMs;
+        }
+
+        public long getAddress(){ return address;}
+
+        public int getRssi(){ return rssi;}
+
+        public long getLastHeardMs(){ return lastHeardMs;}
+    }
+
+    public void observe(long address, int rssi, long nowMs){
+        Neighbor neighbor = neighbors.get(address);
+        if (neighbor == null){
+            neighbors.put(address, new Neighbor(address, rssi, nowMs));
+        } else {
+            neighbor.rssi = rssi;
+            neighbor.lastHeardMs = nowMs;
+        }
+    }
+
+    public List<Long> prune(long nowMs, long timeoutMs){
+        List<Long> removed = new ArrayList<Long>();
+        Iterator<Neighbor> it = neighbors.values().iterator();
+        while (it.hasNext()){
+            Neighbor neighbor = it.next();
+            if (nowMs - neighbor.lastHeardMs > timeoutMs){
+                removed.add(neighbor.address);
+                it.remove();
+            }
+        }
+        return removed;
+    }
+
+    // Strongest signal wins; ties go to the neighbor heard most recently.
+    public Neighbor bestNeighbor(){
+        Neighbor best = null;
+        for (Neighbor neighbor : neighbors.values()){
+            if (best == null || neighbor.rssi > best.rssi
+                    || (neighbor.rssi == best.rssi && neighbor.lastHeardMs > best.lastHeardMs)){
+                best = neighbor;
+            }
+        }
+        return best;
+    }
+
+    public Neighbor get(long address){
+        return neighbors.get(address);
+    }
+
+    public Collection<Neighbor> getNeighbors(){
+        return neighbors.values();
+    }
+
+    public int size(){
+        return neighbors.size();
+    }
+}
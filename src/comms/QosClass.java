@@ -0,0 +1,26 @@
+package comms;
+
+// Declared in drain priority order. Control and Command ride the reliable
+// channel, Telemetry is best-effort, Bulk is fragmented and reliable.
+public enum QosClass {
+    CONTROL(true, false),
+    COMMAND(true, false),
+    TELEMETRY(false, false),
+    BULK(true, true);
+
+    private final boolean reliable;
+    private final boolean fragmented;
+
+    QosClass(boolean reliable, boolean fragmented){
+        this.reliable = reliable;
+        this.fragmented = fragmented;
+    }
+
+    public boolean isReliable(){
+        return reliable;
+    }
+
+    public boolean isFragmented(){
+        return fragmented;
+    }
+}
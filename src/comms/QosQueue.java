@@ -0,0 +1,243 @@
+package comms;
+
+import java.util.ArrayDeque;
+import java.util.Collections;
+import java.util.Deque;
+import java.util.EnumMap;
+import java.util.EnumSet;
+import java.util.List;
+import java.util.Map;
+import java.util.Set;
+
+// Per-class TX queues scheduled one frame at a time. Fragmented items are
+// split on enqueue and only their remaining fragments stay queued, so a
+// Control frame can go out between two Bulk fragments. Classes are drained by
+// strict priority, except that a class below its bandwidth floor, or one
+// passed over starvationLimit times in a row, is served first. With a
+// BandwidthBudget attached, a class registered there under its enum name only
+// sends while the budget grants its next frame; refused frames stay queued
+// and the next class in line is tried instead.
+public class QosQueue {
+    private final int capacityPerClass;
+    private final long accountingWindowBytes;
+    private final Fragmenter fragmenter;
+    private BandwidthBudget budget;
+    private int starvationLimit = Integer.MAX_VALUE;
+    private int nextMessageId;
+    private final Map<QosClass, Deque<Item>> queues = new EnumMap<QosClass, Deque<Item>>(QosClass.class);
+    private final Map<QosClass, Double> floors = new EnumMap<QosClass, Double>(QosClass.class);
+    private final Map<QosClass, Long> windowBytes = new EnumMap<QosClass, Long>(QosClass.class);
+    private final Map<QosClass, Integer> passedOver = new EnumMap<QosClass, Integer>(QosClass.class);
+    private final Map<QosClass, Stats> stats = new EnumMap<QosClass, Stats>(QosClass.class);
+    private long windowTotal;
+
+    public static class Item {
+        private final QosClass qos;
+        private final byte[] payload;
+        private final long enqueuedMs;
+        private final Deque<byte[]> frames = new ArrayDeque<byte[]>();
+        private int framesSent;
+
+        Item(QosClass qos, byte[] payload, long enqueuedMs, List<byte[]> frames){
+            this.qos = qos;
+            this.payload = payload;
+            this.enqueuedMs = enqueuedMs;
+            this.frames.addAll(frames);
+        }
+
+        public QosClass getQos(){ return qos;}
+
+        public byte[] getPayload(){ return payload;}
+
+        public long getEnqueuedMs(){ return enqueuedMs;}
+
+        public int getFramesRemaining(){ return frames.size();}
+
+        public int getFramesSent(){ return framesSent;}
+    }
+
+    public static class Frame {
+        private final Item item;
+        private final byte[] data;
+        private final boolean last;
+
+        Frame(Item item, byte[] data, boolean last){
+            this.item = item;
+            this.data = data;
+            this.last = last;
+        }
+
+        public Item getItem(){ return item;}
+
+        public QosClass getQos(){ return item.qos;}
+
+        public byte[] getData(){ return data;}
+
+        public boolean isLast(){ return last;}
+    }
+
+    public static class Stats {
+        private long queued;
+        private long sent;
+        private long framesSent;
+        private long dropped;
+        private long deferred;
+        private long maxQueueDelayMs;
+        private long lastItemLatencyMs;
+        private long maxItemLatencyMs;
+
+        public long getQueued(){ return queued;}
+
+        public long getSent(){ return sent;}
+
+        public long getFramesSent(){ return framesSent;}
+
+        public long getDropped(){ return dropped;}
+
+        // Polls on which the bandwidth budget held this class's next frame back.
+        public long getDeferred(){ return deferred;}
+
+        public long getMaxQueueDelayMs(){ return maxQueueDelayMs;}
+
+        public long getLastItemLatencyMs(){ return lastItemLatencyMs;}
+
+        public long getMaxItemLatencyMs(){ return maxItemLatencyMs;}
+    }
+
+    public QosQueue(int capacityPerClass, long accountingWindowBytes, Fragmenter fragmenter){
+        this.capacityPerClass = capacityPerClass;
+        this.accountingWindowBytes = accountingWindowBytes;
+        this.fragmenter = fragmenter;
+        for (QosClass qos : QosClass.values()){
+            queues.put(qos, new ArrayDeque<Item>());
+            floors.put(qos, 0.0);
+            windowBytes.put(qos, 0L);
+            passedOver.put(qos, 0);
+            stats.put(qos, new Stats());
+        }
+    }
+
+    public QosQueue(int capacityPerClass, long accountingWindowBytes){
+        this(capacityPerClass, accountingWindowBytes, null);
+    }
+
+    // Fraction (0-1) of sent bytes the class is guaranteed while it has backlog.
+    public void setBandwidthFloor(QosClass qos, double fraction){
+        floors.put(qos, fraction);
+    }
+
+    public void setStarvationLimit(int frames){
+        this.starvationLimit = frames;
+    }
+
+    public void setBudget(BandwidthBudget budget){
+        this.budget = budget;
+    }
+
+    public boolean enqueue(QosClass qos, byte[] payload, long nowMs){
+        Deque<Item> queue = queues.get(qos);
+        if (queue.size() >= capacityPerClass){
+            stats.get(qos).dropped++;
+            return false;
+        }
+        List<byte[]> frames;
+        if (qos.isFragmented() && fragmenter != null){
+            frames = fragmenter.split(nextMessageId++, payload);
+        } else {
+            frames = Collections.singletonList(payload);
+        }
+        queue.addLast(new Item(qos, payload, nowMs, frames));
+        stats.get(qos).queued++;
+        return true;
+    }
+
+    public Frame poll(long nowMs){
+        Set<QosClass> refused = EnumSet.noneOf(QosClass.class);
+        QosClass pick;
+        while ((pick = pickClass(refused)) != null && !granted(pick, nowMs)){
+            stats.get(pick).deferred++;
+            refused.add(pick);
+        }
+        if (pick == null){
+            return null;
+        }
+        for (QosClass qos : QosClass.values()){
+            if (qos == pick || queues.get(qos).isEmpty()){
+                passedOver.put(qos, 0);
+            } else {
+                passedOver.put(qos, passedOver.get(qos) + 1);
+            }
+        }
+        Item item = queues.get(pick).peekFirst();
+        Stats s = stats.get(pick);
+        if (item.framesSent == 0){
+            s.maxQueueDelayMs = Math.max(s.maxQueueDelayMs, nowMs - item.enqueuedMs);
+        }
+        byte[] data = item.frames.pollFirst();
+        item.framesSent++;
+        s.framesSent++;
+        account(pick, data.length);
+        boolean last = item.frames.isEmpty();
+        if (last){
+            queues.get(pick).pollFirst();
+            s.sent++;
+            s.lastItemLatencyMs = nowMs - item.enqueuedMs;
+            s.maxItemLatencyMs = Math.max(s.maxItemLatencyMs, s.lastItemLatencyMs);
+        }
+        return new Frame(item, data, last);
+    }
+
+    public int size(QosClass qos){
+        return queues.get(qos).size();
+    }
+
+    public boolean isEmpty(){
+        for (Deque<Item> queue : queues.values()){
+            if (!queue.isEmpty()){
+                return false;
+            }
+        }
+        return true;
+    }
+
+    public Stats getStats(QosClass qos){
+        return stats.get(qos);
+    }
+
+    private boolean granted(QosClass qos, long nowMs){
+        if (budget == null || !budget.isRegistered(qos.name())){
+            return true;
+        }
+        return budget.tryConsume(qos.name(), queues.get(qos).peekFirst().frames.peekFirst().length, nowMs);
+    }
+
+    private QosClass pickClass(Set<QosClass> refused){
+        QosClass pick = null;
+        for (QosClass qos : QosClass.values()){
+            if (queues.get(qos).isEmpty() || refused.contains(qos)){
+                continue;
+            }
+            if (passedOver.get(qos) >= starvationLimit){
+                return qos;
+            }
+            if (pick == null){
+                pick = qos;
+            } else if (windowTotal > 0 && windowBytes.get(qos) < floors.get(qos) * windowTotal
+                    && windowBytes.get(pick) >= floors.get(pick) * windowTotal){
+                pick = qos;
+            }
+        }
+        return pick;
+    }
+
+    private void account(QosClass qos, int bytes){
+        if (windowTotal >= accountingWindowBytes){
+            windowTotal = 0;
+            for (QosClass c : QosClass.values()){
+                windowBytes.put(c, 0L);
+            }
+        }
+        windowBytes.put(qos, windowBytes.get(qos) + bytes);
+        windowTotal += bytes;
+    }
+}
@@ -0,0 +1,166 @@
+package comms;
+
+import java.io.ByteArrayOutputStream;
+import java.nio.ByteBuffer;
+import java.util.ArrayList;
+import java.util.Arrays;
+import java.util.Collections;
+import java.util.Iterator;
+import java.util.LinkedHashMap;
+import java.util.List;
+import java.util.Map;
+
+public class Reassembler {
+    private final int maxConcurrent;
+    private final long timeoutMs;
+    // Insertion ordered, so the first entry is always the oldest reassembly.
+    private final Map<Integer, Partial> partials = new LinkedHashMap<Integer, Partial>();
+    // Messages delivered within the last timeoutMs, so late duplicates of
+    // their fragments are not mistaken for the start of a new message.
+    private final Map<Integer, Completed> completed = new LinkedHashMap<Integer, Completed>();
+    private long duplicates;
+    private long mismatched;
+    private long crcFailures;
+    private long evicted;
+    private long expired;
+
+    private static class Partial {
+        final byte[][] fragments;
+        final long crc;
+        final long startedMs;
+        int received;
+
+        Partial(int total, long crc, long startedMs){
+            this.fragments = new byte[total][];
+            this.crc = crc;
+            this.startedMs = startedMs;
+        }
+    }
+
+    private static class Completed {
+        final long crc;
+        final long completedMs;
+
+        Completed(long crc, long completedMs){
+            this.crc = crc;
+            this.completedMs = completedMs;
+        }
+    }
+
+    public Reassembler(int maxConcurrent, long timeoutMs){
+        if (maxConcurrent <= 0){
+            throw new IllegalArgumentException("maxConcurrent must be positive : " + maxConcurrent);
+        }
+        this.maxConcurrent = maxConcurrent;
+        this.timeoutMs = timeoutMs;
+    }
+
+    // Returns the whole payload once the last missing fragment arrives and the
+    // CRC matches, otherwise null. Fragments of a message delivered within the
+    // timeout count as duplicates; fragments whose total or CRC disagree with
+    // the message already being reassembled are dropped and counted.
+    public byte[] accept(byte[] fragment, long nowMs){
+        if (fragment.length < Fragmenter.HEADER_LEN){
+            return null;
+        }
+        expire(nowMs);
+        ByteBuffer b = ByteBuffer.wrap(fragment);
+        int messageId = b.getInt();
+        int index = b.getShort() & 0xFFFF;
+        int total = b.getShort() & 0xFFFF;
+        long crc = b.getInt() & 0xFFFFFFFFL;
+        if (total == 0 || index >= total){
+            return null;
+        }
+        Completed done = completed.get(messageId);
+        if (done != null){
+            if (done.crc == crc){
+                duplicates++;
+                return null;
+            }
+            // Same id, different payload: the sender has moved on.
+            completed.remove(messageId);
+        }
+        Partial partial = partials.get(messageId);
+        if (partial == null){
+            if (partials.size() >= maxConcurrent){
+                Iterator<Integer> oldest = partials.keySet().iterator();
+                oldest.next();
+                oldest.remove();
+                evicted++;
+            }
+            partial = new Partial(total, crc, nowMs);
+            partials.put(messageId, partial);
+        } else if (partial.fragments.length != total || partial.crc != crc){
+            mismatched++;
+            return null;
+        }
+        if (partial.fragments[index] != null){
+            duplicates++;
+            return null;
+        }
+        partial.fragments[index] = Arrays.copyOfRange(fragment, Fragmenter.HEADER_LEN, fragment.length);
+        partial.received++;
+        if (partial.received < total){
+            return null;
+        }
+        partials.remove(messageId);
+        ByteArrayOutputStream out = new ByteArrayOutputStream();
+        for (byte[] data : partial.fragments){
+            out.write(data, 0, data.length);
+        }
+        byte[] payload = out.toByteArray();
+        if (Fragmenter.crc32(payload) != crc){
+            crcFailures++;
+            return null;
+        }
+        completed.put(messageId, new Completed(crc, nowMs));
+        return payload;
+    }
+
+    public int expire(long nowMs){
+        Iterator<Completed> done = completed.values().iterator();
+        while (done.hasNext()){
+            if (nowMs - done.next().completedMs > timeoutMs){
+                done.remove();
+            }
+        }
+        int count = 0;
+        Iterator<Partial> it = partials.values().iterator();
+        while (it.hasNext()){
+            if (nowMs - it.next().startedMs > timeoutMs){
+                it.remove();
+                count++;
+            }
+        }
+        expired += count;
+        return count;
+    }
+
+    // Indices still outstanding for a message, for a selective retransmit NACK.
+    public List<Integer> missing(int messageId){
+        Partial partial = partials.get(messageId);
+        if (partial == null){
+            return Collections.emptyList();
+        }
+        List<Integer> missing = new ArrayList<Integer>();
+        for (int i = 0; i < partial.fragments.length; i++){
+            if (partial.fragments[i] == null){
+                missing.add(i);
+            }
+        }
+        return missing;
+    }
+
+    public int pending(){ return partials.size();}
+
+    public long getDuplicates(){ return duplicates;}
+
+    public long getMismatched(){ return mismatched;}
+
+    public long getCrcFailures(){ return crcFailures;}
+
+    public long getEvicted(){ return evicted;}
+
+    public long getExpired(){ return expired;}
+}
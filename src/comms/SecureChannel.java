@@ -0,0 +1,140 @@
+package comms;
+
+import javax.crypto.Cipher;
+import javax.crypto.Mac;
+import javax.crypto.spec.GCMParameterSpec;
+import javax.crypto.spec.SecretKeySpec;
+import java.nio.ByteBuffer;
+import java.nio.charset.StandardCharsets;
+import java.security.GeneralSecurityException;
+import java.security.SecureRandom;
+import java.util.Arrays;
+import java.util.EnumMap;
+import java.util.Map;
+
+// Encrypts and authenticates payloads for one peer. Each direction has its own
+// key and nonce counter; the counter travels in the clear header and doubles as
+// the replay-window sequence number. The AEAD is AES-128-GCM because the
+// project targets Java 8, whose JCE has no ChaCha20-Poly1305; callers only see
+// seal/open, so it can be swapped once the baseline moves. Keys come from
+// SecureHandshake and SecureTransport applies the channel to a Transport.
+public class SecureChannel {
+    public static final int NONCE_LEN = 16;
+    public static final int HEADER_LEN = 8;
+    public static final int TAG_LEN = 16;
+    public static final int REPLAY_WINDOW = 64;
+
+    private static final SecureRandom RANDOM = new SecureRandom();
+
+    private final SecretKeySpec txKey;
+    private final SecretKeySpec rxKey;
+    private long txCounter;
+    private long rxHighest = -1;
+    private long rxWindow;
+    private final Map<SecureChannelException.Reason, Long> rejected =
+            new EnumMap<SecureChannelException.Reason, Long>(SecureChannelException.Reason.class);
+
+    public SecureChannel(byte[] preSharedKey, byte[] initiatorNonce, byte[] responderNonce, boolean initiator){
+        byte[] toResponder = deriveKey(preSharedKey, initiatorNonce, responderNonce, "initiator->responder");
+        byte[] toInitiator = deriveKey(preSharedKey, initiatorNonce, responderNonce, "responder->initiator");
+        txKey = new SecretKeySpec(initiator ? toResponder : toInitiator, "AES");
+        rxKey = new SecretKeySpec(initiator ? toInitiator : toResponder, "AES");
+        for (SecureChannelException.Reason reason : SecureChannelException.Reason.values()){
+            rejected.put(reason, 0L);
+        }
+    }
+
+    public static byte[] newHandshakeNonce(){
+        byte[] nonce = new byte[NONCE_LEN];
+        RANDOM.nextBytes(nonce);
+        return nonce;
+    }
+
+    // HKDF-style extract and expand over HMAC-SHA256, truncated to an AES-128 key.
+    public static byte[] deriveKey(byte[] preSharedKey, byte[] initiatorNonce, byte[] responderNonce, String label){
+        try {
+            Mac mac = Mac.getInstance("HmacSHA256");
+            mac.init(new SecretKeySpec(preSharedKey, "HmacSHA256"));
+            mac.update(initiatorNonce);
+            byte[] prk = mac.doFinal(responderNonce);
+            mac.init(new SecretKeySpec(prk, "HmacSHA256"));
+            mac.update(label.getBytes(StandardCharsets.US_ASCII));
+            return Arrays.copyOf(mac.doFinal(new byte[]{1}), 16);
+        } catch (GeneralSecurityException e){
+            throw new IllegalStateException(e);
+        }
+    }
+
+    public byte[] seal(byte[] plaintext){
+        long counter = txCounter++;
+        byte[] header = ByteBuffer.allocate(HEADER_LEN).putLong(counter).array();
+        try {
+            Cipher cipher = cipher(Cipher.ENCRYPT_MODE, txKey, counter);
+            cipher.updateAAD(header);
+            byte[] body = cipher.doFinal(plaintext);
+            byte[] packet = Arrays.copyOf(header, HEADER_LEN + body.length);
+            System.arraycopy(body, 0, packet, HEADER_LEN, body.length);
+            return packet;
+        } catch (GeneralSecurityException e){
+            throw new IllegalStateException(e);
+        }
+    }
+
+    public byte[] open(byte[] packet) throws SecureChannelException {
+        if (packet.length < HEADER_LEN + TAG_LEN){
+            throw reject(SecureChannelException.Reason.MALFORMED);
+        }
+        long counter = ByteBuffer.wrap(packet, 0, HEADER_LEN).getLong();
+        if (counter < 0){
+            throw reject(SecureChannelException.Reason.MALFORMED);
+        }
+        if (isReplay(counter)){
+            throw reject(SecureChannelException.Reason.REPLAYED);
+        }
+        byte[] plaintext;
+        try {
+            Cipher cipher = cipher(Cipher.DECRYPT_MODE, rxKey, counter);
+            cipher.updateAAD(packet, 0, HEADER_LEN);
+            plaintext = cipher.doFinal(packet, HEADER_LEN, packet.length - HEADER_LEN);
+        } catch (GeneralSecurityException e){
+            throw reject(SecureChannelException.Reason.AUTH_FAILED);
+        }
+        // Only authenticated packets may move the window.
+        accept(counter);
+        return plaintext;
+    }
+
+    public long getRejected(SecureChannelException.Reason reason){
+        return rejected.get(reason);
+    }
+
+    private boolean isReplay(long counter){
+        if (counter > rxHighest){
+            return false;
+        }
+        long age = rxHighest - counter;
+        return age >= REPLAY_WINDOW || (rxWindow & (1L << age)) != 0;
+    }
+
+    private void accept(long counter){
+        if (counter > rxHighest){
+            long shift = counter - rxHighest;
+            rxWindow = shift >= REPLAY_WINDOW ? 1L : (rxWindow << shift) | 1L;
+            rxHighest = counter;
+        } else {
+            rxWindow |= 1L << (rxHighest - counter);
+        }
+    }
+
+    private SecureChannelException reject(SecureChannelException.Reason reason){
+        rejected.put(reason, rejected.get(reason) + 1);
+        return new SecureChannelException(reason);
+    }
+
+    private static Cipher cipher(int mode, SecretKeySpec key, long counter) throws GeneralSecurityException {
+        byte[] iv = ByteBuffer.allocate(12).putInt(0).putLong(counter).array();
+        Cipher cipher = Cipher.getInstance("AES/GCM/NoPadding");
+        cipher.init(mode, key, new GCMParameterSpec(TAG_LEN * 8, iv));
+        return cipher;
+    }
+}
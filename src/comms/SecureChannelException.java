@@ -0,0 +1,18 @@
+package comms;
+
+public class SecureChannelException extends Exception {
+    private static final long serialVersionUID = 1L;
+
+    public enum Reason { MALFORMED, AUTH_FAILED, REPLAYED }
+
+    private final Reason reason;
+
+    public SecureChannelException(Reason reason){
+        super("Packet rejected : " + reason);
+        this.reason = reason;
+    }
+
+    public Reason getReason(){
+        return reason;
+    }
+}
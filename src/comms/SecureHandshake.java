@@ -0,0 +1,80 @@
+package comms;
+
+import java.security.MessageDigest;
+import java.util.Arrays;
+
+// Key-setup exchange for SecureChannel. The initiator sends HELLO with its
+// nonce; the responder answers with its own nonce and a confirmation tag that
+// only a holder of the pre-shared key can compute. Both sides then derive the
+// same session keys from the key and the two nonces.
+//
+//   HELLO: type(1) initiatorNonce(16)
+//   REPLY: type(1) responderNonce(16) confirm(16)
+public class SecureHandshake {
+    public static final byte HELLO = 1;
+    public static final byte REPLY = 2;
+    public static final int HELLO_LEN = 1 + SecureChannel.NONCE_LEN;
+    public static final int CONFIRM_LEN = 16;
+    public static final int REPLY_LEN = 1 + SecureChannel.NONCE_LEN + CONFIRM_LEN;
+
+    private final byte[] preSharedKey;
+    private byte[] initiatorNonce;
+    private SecureChannel channel;
+
+    public SecureHandshake(byte[] preSharedKey){
+        this.preSharedKey = preSharedKey.clone();
+    }
+
+    // Initiator side: the HELLO to send to the peer.
+    public byte[] start(){
+        initiatorNonce = SecureChannel.newHandshakeNonce();
+        byte[] hello = new byte[HELLO_LEN];
+        hello[0] = HELLO;
+        System.arraycopy(initiatorNonce, 0, hello, 1, SecureChannel.NONCE_LEN);
+        return hello;
+    }
+
+    // Responder side: completes the handshake and returns the REPLY to send.
+    public byte[] respond(byte[] hello) throws SecureChannelException {
+        if (hello.length != HELLO_LEN || hello[0] != HELLO){
+            throw new SecureChannelException(SecureChannelException.Reason.MALFORMED);
+        }
+        byte[] theirs = Arrays.copyOfRange(hello, 1, HELLO_LEN);
+        byte[] ours = SecureChannel.newHandshakeNonce();
+        byte[] reply = new byte[REPLY_LEN];
+        reply[0] = REPLY;
+        System.arraycopy(ours, 0, reply, 1, SecureChannel.NONCE_LEN);
+        System.arraycopy(confirm(theirs, ours), 0, reply, 1 + SecureChannel.NONCE_LEN, CONFIRM_LEN);
+        channel = new SecureChannel(preSharedKey, theirs, ours, false);
+        return reply;
+    }
+
+    // Initiator side: checks the peer's REPLY and completes the handshake.
+    public void finish(byte[] reply) throws SecureChannelException {
+        if (initiatorNonce == null){
+            throw new IllegalStateException("start() has not been called");
+        }
+        if (reply.length != REPLY_LEN || reply[0] != REPLY){
+            throw new SecureChannelException(SecureChannelException.Reason.MALFORMED);
+        }
+        byte[] theirs = Arrays.copyOfRange(reply, 1, 1 + SecureChannel.NONCE_LEN);
+        byte[] tag = Arrays.copyOfRange(reply, 1 + SecureChannel.NONCE_LEN, REPLY_LEN);
+        if (!MessageDigest.isEqual(confirm(initiatorNonce, theirs), tag)){
+            throw new SecureChannelException(SecureChannelException.Reason.AUTH_FAILED);
+        }
+        channel = new SecureChannel(preSharedKey, initiatorNonce, theirs, true);
+    }
+
+    public boolean isComplete(){
+        return channel != null;
+    }
+
+    // The session channel, or null until the handshake completes.
+    public SecureChannel getChannel(){
+        return channel;
+    }
+
+    private byte[] confirm(byte[] initiatorNonce, byte[] responderNonce){
+        return SecureChannel.deriveKey(preSharedKey, initiatorNonce, responderNonce, "responder confirm");
+    }
+}
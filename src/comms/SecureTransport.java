@@ -0,0 +1,67 @@
+package comms;
+
+import java.util.Arrays;
+
+// Runs a SecureChannel over any Transport. Packets that fail to open are
+// dropped and counted by the channel, so callers only see authentic payloads.
+// Packets too large for the link or for the caller's buffer are dropped and
+// counted in getOversized() before any crypto work is done on them.
+public class SecureTransport<A> implements Transport<A> {
+    private static final int OVERHEAD = SecureChannel.HEADER_LEN + SecureChannel.TAG_LEN;
+
+    private final Transport<A> inner;
+    private final SecureChannel channel;
+    private final byte[] packet;
+    private long oversized;
+
+    public SecureTransport(Transport<A> inner, SecureChannel channel){
+        this.inner = inner;
+        this.channel = channel;
+        this.packet = new byte[inner.maxPayload()];
+    }
+
+    public int maxPayload(){
+        return inner.maxPayload() - OVERHEAD;
+    }
+
+    public void send(A to, byte[] data) throws CommsException {
+        if (data.length > maxPayload()){
+            throw new CommsException("Payload of " + data.length + " bytes exceeds max " + maxPayload());
+        }
+        inner.send(to, channel.seal(data));
+    }
+
+    public Received<A> pollRecv(byte[] buf) throws CommsException {
+        while (true){
+            Received<A> received = inner.pollRecv(packet);
+            if (received == null){
+                return null;
+            }
+            if (received.getLength() > buf.length + OVERHEAD){
+                oversized++;
+                continue;
+            }
+            byte[] plaintext;
+            try {
+                plaintext = channel.open(Arrays.copyOf(packet, received.getLength()));
+            } catch (SecureChannelException e){
+                continue;
+            }
+            System.arraycopy(plaintext, 0, buf, 0, plaintext.length);
+            return new Received<A>(received.getFrom(), plaintext.length);
+        }
+    }
+
+    // Includes packets the inner transport dropped for exceeding its link.
+    public long getOversized(){
+        return oversized + inner.getOversized();
+    }
+
+    public A localAddr(){
+        return inner.localAddr();
+    }
+
+    public SecureChannel getChannel(){
+        return channel;
+    }
+}
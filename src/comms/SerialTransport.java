@@ -0,0 +1,77 @@
+package comms;
+
+import java.io.IOException;
+import java.io.InputStream;
+import java.io.OutputStream;
+import java.util.ArrayDeque;
+import java.util.Arrays;
+import java.util.Deque;
+
+// SLIP-framed point-to-point link over a serial port's byte streams. There is
+// only one peer, so the address is just the port name.
+public class SerialTransport implements Transport<String> {
+    private final String port;
+    private final InputStream in;
+    private final OutputStream out;
+    private final int maxPayload;
+    private final Slip.Decoder decoder;
+    private final Deque<byte[]> frames = new ArrayDeque<byte[]>();
+    private long oversized;
+
+    public SerialTransport(String port, InputStream in, OutputStream out, int maxPayload){
+        this.port = port;
+        this.in = in;
+        this.out = out;
+        this.maxPayload = maxPayload;
+        this.decoder = new Slip.Decoder(maxPayload);
+    }
+
+    public int maxPayload(){
+        return maxPayload;
+    }
+
+    public void send(String to, byte[] data) throws CommsException {
+        if (data.length > maxPayload){
+            throw new CommsException("Payload of " + data.length + " bytes exceeds max " + maxPayload);
+        }
+        try {
+            out.write(Slip.encode(data));
+            out.flush();
+        } catch (IOException e){
+            throw new CommsException("Write to " + port + " failed", e);
+        }
+    }
+
+    public Received<String> pollRecv(byte[] buf) throws CommsException {
+        try {
+            int available = in.available();
+            if (available > 0){
+                byte[] chunk = new byte[available];
+                int read = in.read(chunk);
+                if (read > 0){
+                    frames.addAll(decoder.feed(read == available ? chunk : Arrays.copyOf(chunk, read)));
+                }
+            }
+        } catch (IOException e){
+            throw new CommsException("Read from " + port + " failed", e);
+        }
+        byte[] frame;
+        while ((frame = frames.pollFirst()) != null){
+            if (frame.length > buf.length){
+                oversized++;
+                continue;
+            }
+            System.arraycopy(frame, 0, buf, 0, frame.length);
+            return new Received<String>(port, frame.length);
+        }
+        return null;
+    }
+
+    public long getOversized(){
+        return oversized;
+    }
+
+    public String localAddr(){
+        return port;
+    }
+}
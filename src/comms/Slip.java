@@ -0,0 +1,149 @@
+package comms;
+
+import java.io.ByteArrayOutputStream;
+import java.util.ArrayList;
+import java.util.List;
+
+// RFC 1055 byte stuffing for links that carry no length framing.
+public final class Slip {
+    public static final int END = 0xC0;
+    public static final int ESC = 0xDB;
+    public static final int ESC_END = 0xDC;
+    public static final int ESC_ESC = 0xDD;
+    // RFC 1055's suggested datagram size.
+    public static final int DEFAULT_MTU = 1006;
+
+    private Slip(){}
+
+    // A leading END flushes any line noise the receiver has buffered.
+    public static byte[] encode(byte[] payload){
+        ByteArrayOutputStream out = new ByteArrayOutputStream(payload.length + 2);
+        out.write(END);
+        for (byte b : payload){
+            int v = b & 0xFF;
+            if (v == END){
+                out.write(ESC);
+                out.write(ESC_END);
+            } else if (v == ESC){
+                out.write(ESC);
+                out.write(ESC_ESC);
+            } else {
+                out.write(v);
+            }
+        }
+        out.write(END);
+        return out.toByteArray();
+    }
+
+    public static byte[] decode(byte[] frame) throws FrameException {
+        int start = 0;
+        int end = frame.length;
+        if (end > 0 && (frame[0] & 0xFF) == END){
+            start++;
+        }
+        if (end > start && (frame[end - 1] & 0xFF) == END){
+            end--;
+        }
+        ByteArrayOutputStream out = new ByteArrayOutputStream(end - start);
+        for (int i = start; i < end; i++){
+            int v = frame[i] & 0xFF;
+            if (v == END){
+                throw new FrameException("Unescaped END inside frame at offset " + i);
+            }
+            if (v != ESC){
+                out.write(v);
+                continue;
+            }
+            if (++i >= end){
+                throw new FrameException("Frame ends with a dangling ESC");
+            }
+            out.write(unescape(frame[i] & 0xFF));
+        }
+        return out.toByteArray();
+    }
+
+    private static int unescape(int v) throws FrameException {
+        if (v == ESC_END){
+            return END;
+        }
+        if (v == ESC_ESC){
+            return ESC;
+        }
+        throw new FrameException("Invalid escape sequence ESC " + Integer.toHexString(v));
+    }
+
+    // Incremental decoder for a byte stream; empty frames between END bytes are
+    // skipped. A frame that grows past the MTU stops buffering and is dropped
+    // at its END, so a stream that never sends END cannot grow the buffer.
+    public static class Decoder {
+        private final int mtu;
+        private final ByteArrayOutputStream current = new ByteArrayOutputStream();
+        private boolean escaped;
+        private boolean corrupt;
+        private boolean oversized;
+        private long errors;
+        private long dropped;
+
+        public Decoder(int mtu){
+            if (mtu <= 0){
+                throw new IllegalArgumentException("MTU must be positive : " + mtu);
+            }
+            this.mtu = mtu;
+        }
+
+        public Decoder(){
+            this(DEFAULT_MTU);
+        }
+
+        public List<byte[]> feed(byte[] data){
+            List<byte[]> frames = new ArrayList<byte[]>();
+            for (byte b : data){
+                int v = b & 0xFF;
+                if (v == END){
+                    if (oversized){
+                        dropped++;
+                    } else if (corrupt || escaped){
+                        errors++;
+                    } else if (current.size() > 0){
+                        frames.add(current.toByteArray());
+                    }
+                    current.reset();
+                    escaped = false;
+                    corrupt = false;
+                    oversized = false;
+                } else if (escaped){
+                    escaped = false;
+                    try {
+                        append(unescape(v));
+                    } catch (FrameException e){
+                        corrupt = true;
+                    }
+                } else if (v == ESC){
+                    escaped = true;
+                } else {
+                    append(v);
+                }
+            }
+            return frames;
+        }
+
+        private void append(int v){
+            if (current.size() >= mtu){
+                oversized = true;
+                current.reset();
+            }
+            if (!oversized){
+                current.write(v);
+            }
+        }
+
+        public long getErrors(){
+            return errors;
+        }
+
+        // Frames discarded for exceeding the MTU.
+        public long getDropped(){
+            return dropped;
+        }
+    }
+}
@@ -0,0 +1,108 @@
+package comms;
+
+import java.io.DataInputStream;
+import java.io.DataOutputStream;
+import java.io.EOFException;
+import java.io.IOException;
+import java.io.InputStream;
+import java.io.OutputStream;
+import java.util.ArrayList;
+import java.util.List;
+
+// Stable on-disk capture format: "BRCP" magic and a version, then one record
+// per frame as [direction u8][timestamp ms i64][peer utf8][len i32][payload].
+// A record longer than the frame limit is treated as corruption, so a damaged
+// length field cannot make the reader allocate an arbitrary amount of memory.
+public final class TrafficCapture {
+    public static final int MAGIC = 0x42524350;
+    public static final int VERSION = 1;
+    // No transport carries a frame larger than a UDP datagram.
+    public static final int MAX_PAYLOAD = 0xFFFF;
+
+    public enum Direction { SENT, RECEIVED }
+
+    public static class Record {
+        private final Direction direction;
+        private final long timestampMs;
+        private final String peer;
+        private final byte[] payload;
+
+        public Record(Direction direction, long timestampMs, String peer, byte[] payload){
+            this.direction = direction;
+            this.timestampMs = timestampMs;
+            this.peer = peer;
+            this.payload = payload;
+        }
+
+        public Direction getDirection(){ return direction;}
+
+        public long getTimestampMs(){ return timestampMs;}
+
+        public String getPeer(){ return peer;}
+
+        public byte[] getPayload(){ return payload;}
+    }
+
+    private TrafficCapture(){}
+
+    public static void write(List<Record> records, OutputStream stream) throws IOException {
+        DataOutputStream out = new DataOutputStream(stream);
+        out.writeInt(MAGIC);
+        out.writeShort(VERSION);
+        for (Record record : records){
+            if (record.payload.length > MAX_PAYLOAD){
+                throw new IOException("Frame of " + record.payload.length + " bytes exceeds max " + MAX_PAYLOAD);
+            }
+            out.writeByte(record.direction.ordinal());
+            out.writeLong(record.timestampMs);
+            out.writeUTF(record.peer);
+            out.writeInt(record.payload.length);
+            out.write(record.payload);
+        }
+        out.flush();
+    }
+
+    public static List<Record> read(InputStream stream) throws CommsException {
+        return read(stream, MAX_PAYLOAD);
+    }
+
+    public static List<Record> read(InputStream stream, int maxPayload) throws CommsException {
+        DataInputStream in = new DataInputStream(stream);
+        List<Record> records = new ArrayList<Record>();
+        try {
+            if (in.readInt() != MAGIC){
+                throw new CommsException("Not a traffic capture");
+            }
+            int version = in.readUnsignedShort();
+            if (version != VERSION){
+                throw new CommsException("Unsupported capture version " + version);
+            }
+            while (true){
+                int direction = in.read();
+                if (direction < 0){
+                    return records;
+                }
+                if (direction >= Direction.values().length){
+                    throw new CommsException("Corrupt record " + records.size());
+                }
+                long timestamp = in.readLong();
+                String peer = in.readUTF();
+                int len = in.readInt();
+                if (len < 0 || len > maxPayload){
+                    throw new CommsException("Corrupt record " + records.size() + " : length " + len);
+                }
+                byte[] payload = new byte[len];
+                in.readFully(payload);
+                records.add(new Record(Direction.values()[direction], timestamp, peer, payload));
+            }
+        } catch (EOFException e){
+            throw new CommsException("Capture truncated after " + records.size() + " records", e);
+        } catch (IOException e){
+            throw new CommsException("Could not read capture", e);
+        }
+    }
+
+    static String peerName(Object address){
+        return address == null ? "" : address.toString();
+    }
+}
@@ -0,0 +1,87 @@
+package comms;
+
+import java.io.IOException;
+import java.io.OutputStream;
+import java.util.ArrayDeque;
+import java.util.ArrayList;
+import java.util.Arrays;
+import java.util.Deque;
+import java.util.List;
+import java.util.function.LongSupplier;
+
+// Transport wrapper that records every frame passing through it. Only the
+// most recent capacity records are kept; older ones are dropped and counted
+// in getDropped().
+public class TrafficRecorder<A> implements Transport<A> {
+    public static final int DEFAULT_CAPACITY = 4096;
+
+    private final Transport<A> inner;
+    private final LongSupplier clock;
+    private final int capacity;
+    private final Deque<TrafficCapture.Record> records = new ArrayDeque<TrafficCapture.Record>();
+    private long dropped;
+
+    public TrafficRecorder(Transport<A> inner, LongSupplier clock, int capacity){
+        if (capacity <= 0){
+            throw new IllegalArgumentException("Capacity must be positive : " + capacity);
+        }
+        this.inner = inner;
+        this.clock = clock;
+        this.capacity = capacity;
+    }
+
+    public TrafficRecorder(Transport<A> inner, LongSupplier clock){
+        this(inner, clock, DEFAULT_CAPACITY);
+    }
+
+    public int maxPayload(){
+        return inner.maxPayload();
+    }
+
+    public void send(A to, byte[] data) throws CommsException {
+        inner.send(to, data);
+        record(new TrafficCapture.Record(TrafficCapture.Direction.SENT, clock.getAsLong(),
+                TrafficCapture.peerName(to), data.clone()));
+    }
+
+    public Received<A> pollRecv(byte[] buf) throws CommsException {
+        Received<A> received = inner.pollRecv(buf);
+        if (received != null){
+            record(new TrafficCapture.Record(TrafficCapture.Direction.RECEIVED, clock.getAsLong(),
+                    TrafficCapture.peerName(received.getFrom()), Arrays.copyOf(buf, received.getLength())));
+        }
+        return received;
+    }
+
+    public long getOversized(){
+        return inner.getOversized();
+    }
+
+    public A localAddr(){
+        return inner.localAddr();
+    }
+
+    public List<TrafficCapture.Record> getRecords(){
+        return new ArrayList<TrafficCapture.Record>(records);
+    }
+
+    public long getDropped(){
+        return dropped;
+    }
+
+    public void export(OutputStream out) throws IOException {
+        TrafficCapture.write(getRecords(), out);
+    }
+
+    public void clear(){
+        records.clear();
+    }
+
+    private void record(TrafficCapture.Record record){
+        if (records.size() == capacity){
+            records.pollFirst();
+            dropped++;
+        }
+        records.addLast(record);
+    }
+}
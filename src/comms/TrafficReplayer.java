@@ -0,0 +1,84 @@
+package comms;
+
+import java.util.ArrayDeque;
+import java.util.ArrayList;
+import java.util.Deque;
+import java.util.List;
+import java.util.function.LongSupplier;
+
+// Plays the inbound half of a capture back into the comms stack. With a clock
+// frames keep their original relative timing, without one they are delivered
+// as fast as they are polled. Outbound frames are collected for comparison.
+public class TrafficReplayer implements Transport<String> {
+    private final Deque<TrafficCapture.Record> inbound = new ArrayDeque<TrafficCapture.Record>();
+    private final List<byte[]> sent = new ArrayList<byte[]>();
+    private final LongSupplier clock;
+    private final int maxPayload;
+    private long captureStartMs;
+    private long replayStartMs = -1;
+    private long oversized;
+
+    public TrafficReplayer(List<TrafficCapture.Record> records, LongSupplier clock, int maxPayload){
+        for (TrafficCapture.Record record : records){
+            if (record.getPayload().length > maxPayload){
+                throw new IllegalArgumentException("Captured frame of " + record.getPayload().length
+                        + " bytes exceeds max " + maxPayload);
+            }
+            if (record.getDirection() == TrafficCapture.Direction.RECEIVED){
+                inbound.addLast(record);
+            }
+        }
+        if (!inbound.isEmpty()){
+            captureStartMs = inbound.peekFirst().getTimestampMs();
+        }
+        this.clock = clock;
+        this.maxPayload = maxPayload;
+    }
+
+    public int maxPayload(){
+        return maxPayload;
+    }
+
+    public void send(String to, byte[] data){
+        sent.add(data.clone());
+    }
+
+    public Received<String> pollRecv(byte[] buf){
+        TrafficCapture.Record next;
+        while ((next = inbound.peekFirst()) != null){
+            if (clock != null){
+                long now = clock.getAsLong();
+                if (replayStartMs < 0){
+                    replayStartMs = now;
+                }
+                if (now - replayStartMs < next.getTimestampMs() - captureStartMs){
+                    return null;
+                }
+            }
+            inbound.pollFirst();
+            if (next.getPayload().length > buf.length){
+                oversized++;
+                continue;
+            }
+            System.arraycopy(next.getPayload(), 0, buf, 0, next.getPayload().length);
+            return new Received<String>(next.getPeer(), next.getPayload().length);
+        }
+        return null;
+    }
+
+    public long getOversized(){
+        return oversized;
+    }
+
+    public String localAddr(){
+        return "replay";
+    }
+
+    public boolean isFinished(){
+        return inbound.isEmpty();
+    }
+
+    public List<byte[]> getSent(){
+        return sent;
+    }
+}
@@ -0,0 +1,33 @@
+package comms;
+
+// Link-agnostic datagram transport so the comms layers run unchanged over
+// UDP, radio or a serial cable. A is the link's address type.
+public interface Transport<A> {
+    int maxPayload();
+
+    void send(A to, byte[] data) throws CommsException;
+
+    // Copies the next datagram into buf, or returns null when none is waiting.
+    // A datagram longer than buf is dropped and counted in getOversized(), and
+    // the one after it is returned instead, so a single bad frame can never
+    // block the link. CommsException means the link itself failed.
+    Received<A> pollRecv(byte[] buf) throws CommsException;
+
+    long getOversized();
+
+    A localAddr();
+
+    class Received<A> {
+        private final A from;
+        private final int length;
+
+        public Received(A from, int length){
+            this.from = from;
+            this.length = length;
+        }
+
+        public A getFrom(){ return from;}
+
+        public int getLength(){ return length;}
+    }
+}
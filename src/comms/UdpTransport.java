@@ -0,0 +1,89 @@
+package comms;
+
+import java.io.IOException;
+import java.net.InetSocketAddress;
+import java.nio.ByteBuffer;
+import java.nio.channels.DatagramChannel;
+
+public class UdpTransport implements Transport<InetSocketAddress> {
+    // Fits an Ethernet MTU without IP fragmentation.
+    public static final int DEFAULT_MAX_PAYLOAD = 1472;
+    // Largest payload an IPv4 UDP datagram can carry.
+    private static final int MAX_DATAGRAM = 65507;
+
+    private final DatagramChannel channel;
+    private final int maxPayload;
+    // Datagrams are received here first; receiving straight into a smaller
+    // buffer would silently truncate them.
+    private final ByteBuffer recvBuf = ByteBuffer.allocate(MAX_DATAGRAM);
+    private long oversized;
+
+    public UdpTransport(InetSocketAddress bind, int maxPayload) throws CommsException {
+        this.maxPayload = maxPayload;
+        try {
+            channel = DatagramChannel.open();
+            channel.configureBlocking(false);
+            channel.bind(bind);
+        } catch (IOException e){
+            throw new CommsException("Could not bind " + bind, e);
+        }
+    }
+
+    public UdpTransport(InetSocketAddress bind) throws CommsException {
+        this(bind, DEFAULT_MAX_PAYLOAD);
+    }
+
+    public int maxPayload(){
+        return maxPayload;
+    }
+
+    public void send(InetSocketAddress to, byte[] data) throws CommsException {
+        if (data.length > maxPayload){
+            throw new CommsException("Payload of " + data.length + " bytes exceeds max " + maxPayload);
+        }
+        try {
+            channel.send(ByteBuffer.wrap(data), to);
+        } catch (IOException e){
+            throw new CommsException("Send to " + to + " failed", e);
+        }
+    }
+
+    public Received<InetSocketAddress> pollRecv(byte[] buf) throws CommsException {
+        while (true){
+            recvBuf.clear();
+            InetSocketAddress from;
+            try {
+                from = (InetSocketAddress) channel.receive(recvBuf);
+            } catch (IOException e){
+                throw new CommsException("Receive failed", e);
+            }
+            if (from == null){
+                return null;
+            }
+            recvBuf.flip();
+            int length = recvBuf.remaining();
+            if (length > buf.length){
+                oversized++;
+                continue;
+            }
+            recvBuf.get(buf, 0, length);
+            return new Received<InetSocketAddress>(from, length);
+        }
+    }
+
+    public long getOversized(){
+        return oversized;
+    }
+
+    public InetSocketAddress localAddr(){
+        try {
+            return (InetSocketAddress) channel.getLocalAddress();
+        } catch (IOException e){
+            return null;
+        }
+    }
+
+    public void close() throws IOException {
+        channel.close();
+    }
+}
@@ -1,4 +1,263 @@
 package model;
 
+import Util.Enums.Role;
+
+import java.nio.ByteBuffer;
+import java.util.ArrayList;
+import java.util.Arrays;
+import java.util.Collections;
+import java.util.Iterator;
+import java.util.LinkedHashMap;
+import java.util.List;
+import java.util.Map;
+import java.util.Random;
+import java.util.TreeMap;
+
 public class Swarm {
+    // Local id of a swarm built without one; such a swarm cannot gossip.
+    public static final long NO_LOCAL_ID = -1;
+    // How long a pruned member stays barred from coming back on old versions.
+    public static final long DEFAULT_REJOIN_GUARD_MS = 30000;
+
+    // Roles that must each be held by exactly one member; everyone else scouts.
+    private final List<Role> requiredRoles;
+    private final long localId;
+    private final Map<Long, Member> members = new TreeMap<Long, Member>();
+    // Last known version of each pruned member. Gossip can only bring a pruned
+    // member back with a strictly newer version, i.e. proof it is still alive.
+    // Tombstones are forgotten after the rejoin guard so the map stays bounded.
+    private final Map<Long, Tombstone> tombstones = new TreeMap<Long, Tombstone>();
+    private long rejoinGuardMs = DEFAULT_REJOIN_GUARD_MS;
+
+    private static class Tombstone {
+        private final long version;
+        private final long prunedMs;
+
+        Tombstone(long version, long prunedMs){
+            this.version = version;
+            this.prunedMs = prunedMs;
+        }
+    }
+
+    public static class Member {
+        private final long nodeId;
+        private Node node;
+        private long lastHeardMs;
+        private long lastAdvancedMs;
+        private long version;
+        private Role role;
+
+        Member(long nodeId, Node node, long lastHeardMs){
+            this.nodeId = nodeId;
+            this.node = node;
+            this.lastHeardMs = lastHeardMs;
+            this.lastAdvancedMs = lastHeardMs;
+        }
+
+        public long getNodeId(){ return nodeId;}
+
+        // Null for members only known through gossip.
+        public Node getNode(){ return node;}
+
+        public long getVersion(){ return version;}
+
+        // Last direct contact, -1 for members only known through gossip.
+        public long getLastHeardMs(){ return lastHeardMs;}
+
+        // Last direct contact or gossiped version increase, whichever is later.
+        public long getLastAdvancedMs(){ return lastAdvancedMs;}
+
+        public Role getRole(){ return role;}
+    }
+
+    public Swarm(){
+        this(NO_LOCAL_ID);
+    }
+
+    public Swarm(List<Role> requiredRoles){
+        this(NO_LOCAL_ID, requiredRoles);
+    }
+
+    public Swarm(long localId){
+        this(localId, Arrays.asList(Role.LEADER, Role.RELAY));
+    }
+
+    public Swarm(long localId, List<Role> requiredRoles){
+        this.localId = localId;
+        this.requiredRoles = new ArrayList<Role>(requiredRoles);
+    }
+
+    public long getLocalId(){
+        return localId;
+    }
+
+    public void setRejoinGuardMs(long rejoinGuardMs){
+        this.rejoinGuardMs = rejoinGuardMs;
+    }
+
+    public long getRejoinGuardMs(){
+        return rejoinGuardMs;
+    }
+
+    public int getTombstoneCount(){
+        return tombstones.size();
+    }
+
+    public void join(Node node, long nowMs){
+        Member member = members.get(node.getNode_id());
+        if (member == null){
+            members.put(node.getNode_id(), new Member(node.getNode_id(), node, nowMs));
+            tombstones.remove(node.getNode_id());
+        } else {
+            member.node = node;
+            member.lastHeardMs = nowMs;
+            member.lastAdvancedMs = nowMs;
+        }
+    }
+
+    public boolean heard(long nodeId, long nowMs){
+        Member member = members.get(nodeId);
+        if (member == null){
+            return false;
+        }
+        member.lastHeardMs = nowMs;
+        member.lastAdvancedMs = nowMs;
+        return true;
+    }
+
+    public List<Long> pruneStale(long nowMs, long timeoutMs){
+        expireTombstones(nowMs);
+        List<Long> removed = new ArrayList<Long>();
+        Iterator<Member> it = members.values().iterator();
+        while (it.hasNext()){
+            Member member = it.next();
+            if (nowMs - member.lastAdvancedMs > timeoutMs){
+                removed.add(member.nodeId);
+                tombstones.put(member.nodeId, new Tombstone(member.version, nowMs));
+                it.remove();
+            }
+        }
+        return removed;
+    }
+
+    // Deterministic: current holders keep their role, vacant required roles go
+    // to the lowest node ids not already holding one.
+    public void assignRoles(){
+        List<Role> vacant = new ArrayList<Role>(requiredRoles);
+        for (Member member : members.values()){
+            if (member.role != null && vacant.remove(member.role)){
+                continue;
+            }
+            member.role = null;
+        }
+        for (Member member : members.values()){
+            if (member.role == null){
+                member.role = vacant.isEmpty() ? Role.SCOUT : vacant.remove(0);
+            }
+        }
+    }
+
+    // Bumps our own heartbeat version and picks up to fanout random peers to
+    // send our digest of (node id, version) pairs to.
+    public Map<Long, byte[]> gossipTick(Random rng, int fanout, long nowMs){
+        if (localId == NO_LOCAL_ID){
+            throw new IllegalStateException("A swarm without a local id cannot gossip");
+        }
+        Member self = members.get(localId);
+        if (self == null){
+            self = new Member(localId, null, nowMs);
+            members.put(localId, self);
+        }
+        self.version++;
+        self.lastHeardMs = nowMs;
+        self.lastAdvancedMs = nowMs;
+        List<Long> peers = new ArrayList<Long>(members.keySet());
+        peers.remove(localId);
+        Collections.shuffle(peers, rng);
+        byte[] digest = digest();
+        Map<Long, byte[]> out = new LinkedHashMap<Long, byte[]>();
+        for (Long peer : peers.subList(0, Math.min(fanout, peers.size()))){
+            out.put(peer, digest);
+        }
+        return out;
+    }
+
+    // Digest layout: int count, then count (long node id, long version) pairs.
+    // Adopts every entry newer than what we hold. A second-hand version bump
+    // keeps a member from being pruned but does not count as hearing from it;
+    // only the sender itself is heard directly. Returns false, changing
+    // nothing, for a digest whose length does not match its count.
+    public boolean mergeGossip(long from, byte[] digest, long nowMs){
+        if (digest.length < 4){
+            return false;
+        }
+        ByteBuffer buffer = ByteBuffer.wrap(digest);
+        int count = buffer.getInt();
+        if (count < 0 || digest.length != 4 + (long) count * 16){
+            return false;
+        }
+        expireTombstones(nowMs);
+        if (!heard(from, nowMs) && from != localId){
+            members.put(from, new Member(from, null, nowMs));
+            tombstones.remove(from);
+        }
+        for (int i = 0; i < count; i++){
+            long nodeId = buffer.getLong();
+            long version = buffer.getLong();
+            if (nodeId == localId){
+                continue;
+            }
+            Member member = members.get(nodeId);
+            if (member == null){
+                Tombstone pruned = tombstones.get(nodeId);
+                if (pruned != null && version <= pruned.version){
+                    continue;
+                }
+                tombstones.remove(nodeId);
+                member = new Member(nodeId, null, -1);
+                member.lastAdvancedMs = nowMs;
+                member.version = version;
+                members.put(nodeId, member);
+            } else if (version > member.version){
+                member.version = version;
+                member.lastAdvancedMs = nowMs;
+            }
+        }
+        return true;
+    }
+
+    private void expireTombstones(long nowMs){
+        Iterator<Tombstone> it = tombstones.values().iterator();
+        while (it.hasNext()){
+            if (nowMs - it.next().prunedMs > rejoinGuardMs){
+                it.remove();
+            }
+        }
+    }
+
+    private byte[] digest(){
+        ByteBuffer buffer = ByteBuffer.allocate(4 + members.size() * 16);
+        buffer.putInt(members.size());
+        for (Member member : members.values()){
+            buffer.putLong(member.nodeId).putLong(member.version);
+        }
+        return buffer.array();
+    }
+
+    public Role roleOf(long nodeId){
+        Member member = members.get(nodeId);
+        return member == null ? null : member.role;
+    }
+
+    public Member getMember(long nodeId){
+        return members.get(nodeId);
+    }
+
+    public List<Member> getMembers(){
+        return new ArrayList<Member>(members.values());
+    }
+
+    public int size(){
+        return members.size();
+    }
 }
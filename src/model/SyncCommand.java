@@ -0,0 +1,90 @@
+package model;
+
+import Util.Enums.DroneCommand;
+import Util.Enums.RoundState;
+
+import java.util.ArrayList;
+import java.util.Collection;
+import java.util.List;
+import java.util.TreeSet;
+
+// Leader-side coordinator for swarm-wide commands: a command is only carried
+// out once every follower has acknowledged it. Each issue starts a new round;
+// acks carry the round number so late acks from an earlier round are ignored.
+public class SyncCommand {
+    private final long timeoutMs;
+    private DroneCommand command;
+    private int round;
+    private long issuedMs;
+    private RoundState state = RoundState.IDLE;
+    private final TreeSet<Long> expected = new TreeSet<Long>();
+    private final TreeSet<Long> acked = new TreeSet<Long>();
+
+    public SyncCommand(long timeoutMs){
+        this.timeoutMs = timeoutMs;
+    }
+
+    // Starts a round and returns its number, to be sent along with the command.
+    public int issue(DroneCommand cmd, Collection<Long> followers, long nowMs){
+        command = cmd;
+        round++;
+        issuedMs = nowMs;
+        expected.clear();
+        expected.addAll(followers);
+        acked.clear();
+        state = expected.isEmpty() ? RoundState.COMPLETE : RoundState.PENDING;
+        return round;
+    }
+
+    // Every member of the swarm except the local node is a follower.
+    public int issue(DroneCommand cmd, Swarm swarm, long nowMs){
+        List<Long> followers = new ArrayList<Long>();
+        for (Swarm.Member member : swarm.getMembers()){
+            if (member.getNodeId() != swarm.getLocalId()){
+                followers.add(member.getNodeId());
+            }
+        }
+        return issue(cmd, followers, nowMs);
+    }
+
+    // Returns false for acks from unknown nodes, for other rounds, or after
+    // the round has already completed or timed out.
+    public boolean onAck(long from, int ackRound){
+        if (state != RoundState.PENDING || ackRound != round || !expected.contains(from)){
+            return false;
+        }
+        acked.add(from);
+        if (acked.size() == expected.size()){
+            state = RoundState.COMPLETE;
+        }
+        return true;
+    }
+
+    public RoundState poll(long nowMs){
+        if (state == RoundState.PENDING && nowMs - issuedMs >= timeoutMs){
+            state = RoundState.TIMED_OUT;
+        }
+        return state;
+    }
+
+    public boolean allAcked(){
+        return state == RoundState.COMPLETE;
+    }
+
+    // Followers that have not acknowledged the current round, lowest id first.
+    public List<Long> nonResponders(){
+        List<Long> missing = new ArrayList<Long>();
+        for (Long id : expected){
+            if (!acked.contains(id)){
+                missing.add(id);
+            }
+        }
+        return missing;
+    }
+
+    public DroneCommand getCommand(){ return command;}
+
+    public int getRound(){ return round;}
+
+    public RoundState getState(){ return state;}
+}
@@ -74,6 +74,9 @@ public abstract class Vertex {
       private Health health = null;
       private WingType wingType =  WingType.FIXED_WING;
 
+      public T nodeId(long val){
+         node_id=val; return self();
+      }
       public T callSign(String val){
          callSign=val; return self() ;
       }
@@ -0,0 +1,79 @@
+package navigation;
+
+import Util.Enums.OverflowPolicy;
+import Util.Math3d.Vec3;
+import Util.RingBuffer;
+
+import java.nio.ByteBuffer;
+import java.nio.ByteOrder;
+import java.util.ArrayList;
+import java.util.List;
+
+// Black box for post-flight analysis: the last capacity commanded/measured
+// position pairs, oldest dropped first.
+public class FlightRecorder {
+    // u32 count, then per record an i64 timestamp and six f32 (commanded
+    // x, y, z, measured x, y, z), all little-endian.
+    public static final int RECORD_LEN = 32;
+
+    private final RingBuffer<Record> records;
+
+    public static class Record {
+        private final long timestampMs;
+        private final Vec3 commanded;
+        private final Vec3 measured;
+
+        public Record(long timestampMs, Vec3 commanded, Vec3 measured){
+            this.timestampMs = timestampMs;
+            this.commanded = commanded;
+            this.measured = measured;
+        }
+
+        public long getTimestampMs(){ return timestampMs;}
+
+        public Vec3 getCommanded(){ return commanded;}
+
+        public Vec3 getMeasured(){ return measured;}
+
+        public Vec3 getError(){ return commanded.sub(measured);}
+    }
+
+    public FlightRecorder(int capacity){
+        this.records = new RingBuffer<Record>(capacity, OverflowPolicy.OVERWRITE);
+    }
+
+    public void update(long timestampMs, Vec3 commanded, Vec3 measured){
+        records.push(new Record(timestampMs, commanded, measured));
+    }
+
+    public List<Record> getRecords(){
+        List<Record> out = new ArrayList<Record>(records.len());
+        for (Record record : records){
+            out.add(record);
+        }
+        return out;
+    }
+
+    public int size(){
+        return records.len();
+    }
+
+    public void clear(){
+        records.clear();
+    }
+
+    public byte[] export(){
+        ByteBuffer buffer = ByteBuffer.allocate(4 + records.len() * RECORD_LEN).order(ByteOrder.LITTLE_ENDIAN);
+        buffer.putInt(records.len());
+        for (Record record : records){
+            buffer.putLong(record.timestampMs);
+            putVec(buffer, record.commanded);
+            putVec(buffer, record.measured);
+        }
+        return buffer.array();
+    }
+
+    private static void putVec(ByteBuffer buffer, Vec3 v){
+        buffer.putFloat(v.x).putFloat(v.y).putFloat(v.z);
+    }
+}
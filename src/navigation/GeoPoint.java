@@ -0,0 +1,31 @@
+package navigation;
+
+public class GeoPoint {
+    private final double latDeg;
+    private final double lonDeg;
+    private final double altM;
+
+    public GeoPoint(double latDeg, double lonDeg, double altM){
+        if (latDeg < -90 || latDeg > 90){
+            throw new IllegalArgumentException("Latitude out of range : " + latDeg);
+        }
+        this.latDeg = latDeg;
+        this.lonDeg = Geodesy.normalizeLon(lonDeg);
+        this.altM = altM;
+    }
+
+    public GeoPoint(double latDeg, double lonDeg){
+        this(latDeg, lonDeg, 0);
+    }
+
+    public double getLatDeg(){ return latDeg;}
+
+    public double getLonDeg(){ return lonDeg;}
+
+    public double getAltM(){ return altM;}
+
+    @Override
+    public String toString(){
+        return String.format("(%.7f, %.7f, %.2fm)", latDeg, lonDeg, altM);
+    }
+}
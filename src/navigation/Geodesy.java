@@ -0,0 +1,56 @@
+package navigation;
+
+// Great-circle helpers on a spherical earth (mean radius), good to about
+// 0.5% against the WGS84 ellipsoid. Longitudes are normalized to [-180, 180)
+// so paths across the antimeridian take the short way round.
+public final class Geodesy {
+    public static final double EARTH_RADIUS_M = 6371008.8;
+
+    private Geodesy(){}
+
+    public static double distanceM(GeoPoint a, GeoPoint b){
+        double lat1 = Math.toRadians(a.getLatDeg());
+        double lat2 = Math.toRadians(b.getLatDeg());
+        double dLat = lat2 - lat1;
+        double dLon = Math.toRadians(normalizeLon(b.getLonDeg() - a.getLonDeg()));
+        double h = Math.sin(dLat / 2) * Math.sin(dLat / 2)
+                + Math.cos(lat1) * Math.cos(lat2) * Math.sin(dLon / 2) * Math.sin(dLon / 2);
+        return 2 * EARTH_RADIUS_M * Math.asin(Math.min(1, Math.sqrt(h)));
+    }
+
+    // Initial bearing from a to b, clockwise from true north in [0, 360).
+    public static double bearingDeg(GeoPoint a, GeoPoint b){
+        double lat1 = Math.toRadians(a.getLatDeg());
+        double lat2 = Math.toRadians(b.getLatDeg());
+        double dLon = Math.toRadians(normalizeLon(b.getLonDeg() - a.getLonDeg()));
+        double y = Math.sin(dLon) * Math.cos(lat2);
+        double x = Math.cos(lat1) * Math.sin(lat2) - Math.sin(lat1) * Math.cos(lat2) * Math.cos(dLon);
+        return normalizeBearing(Math.toDegrees(Math.atan2(y, x)));
+    }
+
+    // Point reached by travelling distanceM along the great circle that
+    // starts at from with the given bearing. Altitude is carried over.
+    public static GeoPoint destination(GeoPoint from, double bearingDeg, double distanceM){
+        double lat1 = Math.toRadians(from.getLatDeg());
+        double lon1 = Math.toRadians(from.getLonDeg());
+        double brg = Math.toRadians(bearingDeg);
+        double d = distanceM / EARTH_RADIUS_M;
+        double lat2 = Math.asin(Math.sin(lat1) * Math.cos(d) + Math.cos(lat1) * Math.sin(d) * Math.cos(brg));
+        double lon2 = lon1 + Math.atan2(Math.sin(brg) * Math.sin(d) * Math.cos(lat1),
+                Math.cos(d) - Math.sin(lat1) * Math.sin(lat2));
+        return new GeoPoint(Math.toDegrees(lat2), Math.toDegrees(lon2), from.getAltM());
+    }
+
+    public static double normalizeLon(double lonDeg){
+        double lon = (lonDeg + 180) % 360;
+        if (lon < 0){
+            lon += 360;
+        }
+        return lon - 180;
+    }
+
+    public static double normalizeBearing(double deg){
+        double b = deg % 360;
+        return b < 0 ? b + 360 : b;
+    }
+}
@@ -0,0 +1,48 @@
+package navigation;
+
+import Util.Math3d.Vec3;
+
+// Local east-north-up frame anchored at an origin, in meters: x east, y north,
+// z up. Uses the WGS84 meridian and prime-vertical radii at the origin
+// (small-angle ENU), which stays within 0.1% of the distance from the origin
+// out to about 10 km. Accuracy degrades with distance and towards the poles,
+// so origins above 89 degrees of latitude are refused.
+public class LocalFrame {
+    public static final double MAX_ORIGIN_LAT_DEG = 89;
+
+    private static final double WGS84_A = 6378137.0;
+    private static final double WGS84_E2 = 6.69437999014e-3;
+
+    private final GeoPoint origin;
+    private final double metersPerRadLat;
+    private final double metersPerRadLon;
+
+    public LocalFrame(GeoPoint origin){
+        if (Math.abs(origin.getLatDeg()) > MAX_ORIGIN_LAT_DEG){
+            throw new IllegalArgumentException("Local frame origin too close to a pole : " + origin);
+        }
+        this.origin = origin;
+        double lat = Math.toRadians(origin.getLatDeg());
+        double s = Math.sin(lat);
+        double w = Math.sqrt(1 - WGS84_E2 * s * s);
+        double meridian = WGS84_A * (1 - WGS84_E2) / (w * w * w);
+        double primeVertical = WGS84_A / w;
+        this.metersPerRadLat = meridian + origin.getAltM();
+        this.metersPerRadLon = (primeVertical + origin.getAltM()) * Math.cos(lat);
+    }
+
+    public GeoPoint getOrigin(){ return origin;}
+
+    public Vec3 toLocal(GeoPoint p){
+        double dLat = Math.toRadians(p.getLatDeg() - origin.getLatDeg());
+        double dLon = Math.toRadians(Geodesy.normalizeLon(p.getLonDeg() - origin.getLonDeg()));
+        return new Vec3((float) (dLon * metersPerRadLon), (float) (dLat * metersPerRadLat),
+                (float) (p.getAltM() - origin.getAltM()));
+    }
+
+    public GeoPoint toGeo(Vec3 local){
+        double lat = origin.getLatDeg() + Math.toDegrees(local.y / metersPerRadLat);
+        double lon = origin.getLonDeg() + Math.toDegrees(local.x / metersPerRadLon);
+        return new GeoPoint(Math.max(-90, Math.min(90, lat)), lon, origin.getAltM() + local.z);
+    }
+}
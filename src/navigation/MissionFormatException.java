@@ -0,0 +1,9 @@
+package navigation;
+
+public class MissionFormatException extends Exception {
+    private static final long serialVersionUID = 1L;
+
+    public MissionFormatException(String message){
+        super(message);
+    }
+}
@@ -0,0 +1,23 @@
+package navigation;
+
+public class Waypoint {
+    private final float x;
+    private final float y;
+    private final float z;
+    private final float tolerance;
+
+    public Waypoint(float x, float y, float z, float tolerance){
+        this.x = x;
+        this.y = y;
+        this.z = z;
+        this.tolerance = tolerance;
+    }
+
+    public float getX(){ return x;}
+
+    public float getY(){ return y;}
+
+    public float getZ(){ return z;}
+
+    public float getTolerance(){ return tolerance;}
+}
@@ -0,0 +1,85 @@
+package navigation;
+
+import Util.Math3d.Vec3;
+
+import java.nio.BufferUnderflowException;
+import java.nio.ByteBuffer;
+import java.nio.ByteOrder;
+import java.util.ArrayDeque;
+import java.util.ArrayList;
+import java.util.Deque;
+import java.util.List;
+
+public class WaypointNavigator {
+    // u32 count followed by x, y, z, tolerance as little-endian f32 per waypoint.
+    public static final int WAYPOINT_LEN = 16;
+
+    private final Deque<Waypoint> queue = new ArrayDeque<Waypoint>();
+    private FlightRecorder recorder;
+
+    // Every update() is logged to the recorder while one is attached.
+    public void setRecorder(FlightRecorder recorder){
+        this.recorder = recorder;
+    }
+
+    public FlightRecorder getRecorder(){ return recorder;}
+
+    public void addWaypoint(Waypoint waypoint){
+        queue.addLast(waypoint);
+    }
+
+    public Waypoint currentWaypoint(){
+        return queue.peekFirst();
+    }
+
+    public Waypoint advance(){
+        return queue.pollFirst();
+    }
+
+    // Feeds the measured position: it is recorded against the active waypoint,
+    // which is dropped once the position is within its tolerance. Returns the
+    // waypoint to fly to next, or null when the mission is done.
+    public Waypoint update(long nowMs, Vec3 measured){
+        Waypoint current = queue.peekFirst();
+        if (current == null){
+            return null;
+        }
+        Vec3 commanded = new Vec3(current.getX(), current.getY(), current.getZ());
+        if (recorder != null){
+            recorder.update(nowMs, commanded, measured);
+        }
+        if (commanded.sub(measured).norm() <= current.getTolerance()){
+            queue.pollFirst();
+        }
+        return queue.peekFirst();
+    }
+
+    public int remaining(){
+        return queue.size();
+    }
+
+    public void clear(){
+        queue.clear();
+    }
+
+    // Parses the whole mission before touching the queue, so a malformed
+    // upload leaves the current mission intact.
+    public int loadMission(byte[] bytes) throws MissionFormatException {
+        ByteBuffer buffer = ByteBuffer.wrap(bytes).order(ByteOrder.LITTLE_ENDIAN);
+        List<Waypoint> mission = new ArrayList<Waypoint>();
+        try {
+            long count = buffer.getInt() & 0xFFFFFFFFL;
+            if (buffer.remaining() != count * WAYPOINT_LEN){
+                throw new MissionFormatException("Expected " + count + " waypoints but buffer holds "
+                        + buffer.remaining() + " bytes");
+            }
+            for (long i = 0; i < count; i++){
+                mission.add(new Waypoint(buffer.getFloat(), buffer.getFloat(), buffer.getFloat(), buffer.getFloat()));
+            }
+        } catch (BufferUnderflowException e){
+            throw new MissionFormatException("Truncated mission header");
+        }
+        queue.addAll(mission);
+        return mission.size();
+    }
+}
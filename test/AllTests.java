@@ -0,0 +1,41 @@
+public class AllTests {
+    public static void main(String[] args){
+        Util.Config.ConfigTest.run();
+        Util.CrcTest.run();
+        Util.DeadlineSupervisorTest.run();
+        Util.EventBusTest.run();
+        Util.FiltersTest.run();
+        Util.FlagSetTest.run();
+        Util.FixedTest.run();
+        Util.IdSpaceTest.run();
+        Util.IndexedPriorityQueueTest.run();
+        Util.KalmanTest.run();
+        Util.RingBufferTest.run();
+        Util.Rate.RateTest.run();
+        Util.StateMachineTest.run();
+        Util.TimerWheelTest.run();
+        Util.Logging.LoggerTest.run();
+        Util.Logging.SuppressingSinkTest.run();
+        Util.Math3d.Math3dTest.run();
+        Util.Time.TimeTest.run();
+        Util.Units.UnitsTest.run();
+        model.SwarmTest.run();
+        model.SyncCommandTest.run();
+        navigation.FlightRecorderTest.run();
+        navigation.GeodesyTest.run();
+        navigation.WaypointNavigatorTest.run();
+        comms.BandwidthBudgetTest.run();
+        comms.CompressionTest.run();
+        comms.FragmenterTest.run();
+        comms.LinkMonitorTest.run();
+        comms.MavlinkTest.run();
+        comms.NeighborTableTest.run();
+        comms.QosQueueTest.run();
+        comms.SecureChannelTest.run();
+        comms.SlipTest.run();
+        comms.SwarmLinkTest.run();
+        comms.TrafficCaptureTest.run();
+        comms.TransportTest.run();
+        Util.Check.exit();
+    }
+}
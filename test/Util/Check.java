@@ -0,0 +1,72 @@
+package Util;
+
+import java.util.ArrayList;
+import java.util.List;
+
+// Minimal test harness; the project has no build tool or test framework.
+// Each test class exposes run(), which registers its cases here, and a main()
+// that runs it alone. AllTests runs every class. Compile src and test
+// together and run with:
+//   java -cp out AllTests
+public final class Check {
+    public interface Case {
+        void run() throws Exception;
+    }
+
+    private static int passed;
+    private static final List<String> failures = new ArrayList<String>();
+
+    private Check(){}
+
+    public static void test(String name, Case body){
+        try {
+            body.run();
+            passed++;
+        } catch (Throwable t){
+            failures.add(name + ": " + t);
+        }
+    }
+
+    public static void isTrue(boolean condition, String message){
+        if (!condition){
+            throw new AssertionError(message);
+        }
+    }
+
+    public static void isFalse(boolean condition, String message){
+        isTrue(!condition, message);
+    }
+
+    public static void equal(Object expected, Object actual){
+        if (expected == null ? actual != null : !expected.equals(actual)){
+            throw new AssertionError("expected <" + expected + "> but was <" + actual + ">");
+        }
+    }
+
+    public static void near(double expected, double actual, double tolerance){
+        if (!(Math.abs(expected - actual) <= tolerance)){
+            throw new AssertionError("expected " + expected + " +/- " + tolerance + " but was " + actual);
+        }
+    }
+
+    public static <T extends Throwable> T throwsA(Class<T> type, Case body){
+        try {
+            body.run();
+        } catch (Throwable t){
+            if (type.isInstance(t)){
+                return type.cast(t);
+            }
+            throw new AssertionError("expected " + type.getSimpleName() + " but got " + t);
+        }
+        throw new AssertionError("expected " + type.getSimpleName() + " but nothing was thrown");
+    }
+
+    // Prints a summary and exits non-zero if anything failed.
+    public static void exit(){
+        for (String failure : failures){
+            System.out.println("FAIL " + failure);
+        }
+        System.out.println(passed + " passed, " + failures.size() + " failed");
+        System.exit(failures.isEmpty() ? 0 : 1);
+    }
+}
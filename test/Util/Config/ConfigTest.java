@@ -0,0 +1,102 @@
+package Util.Config;
+
+import Util.Check;
+
+import java.util.Arrays;
+import java.util.Collections;
+import java.util.HashMap;
+import java.util.List;
+import java.util.Map;
+
+public class ConfigTest {
+    private static final String FIXTURE = String.join("\n",
+            "# platform choice",
+            "platform = \"tello\"",
+            "",
+            "[nav.pid.altitude]",
+            "kp = 1.5   # proportional",
+            "ki = 0",
+            "enabled = true",
+            "gains = [1, 2.5, \"x,y\"]",
+            "[net]",
+            "maxPeers = 8",
+            "label = \"a # not a comment\"");
+
+    public static void run(){
+        Check.test("Config parses sections, comments and every type", () -> {
+            Config config = Config.parse(FIXTURE);
+            Check.equal("tello", config.getString("platform"));
+            Check.near(1.5, config.getDouble("nav.pid.altitude.kp"), 0);
+            Check.equal(0L, config.getLong("nav.pid.altitude.ki"));
+            Check.isTrue(config.getBool("nav.pid.altitude.enabled"), "bool");
+            Check.equal(Arrays.<Object>asList(1L, 2.5, "x,y"), config.getList("nav.pid.altitude.gains"));
+            Check.equal("a # not a comment", config.getString("net.label"));
+            Check.equal(8, config.section("net").getInt("maxPeers"));
+        });
+
+        Check.test("Config reports every bad line with its number", () -> {
+            ConfigException e = Check.throwsA(ConfigException.class, () -> Config.parse("a = 1\n[bad\nnovalue\nb = \"open\nc = 2"));
+            List<String> problems = e.getProblems();
+            Check.equal(3, problems.size());
+            Check.isTrue(problems.get(0).startsWith("line 2:"), problems.get(0));
+            Check.isTrue(problems.get(1).startsWith("line 3:"), problems.get(1));
+            Check.isTrue(problems.get(2).startsWith("line 4:"), problems.get(2));
+        });
+
+        Check.test("Config coerces integers to floats but not back", () -> {
+            Config config = Config.parse("i = 3\nf = 3.0");
+            Check.near(3.0, config.getDouble("i"), 0);
+            Check.throwsA(IllegalStateException.class, () -> config.getLong("f"));
+            Check.throwsA(IllegalStateException.class, () -> config.getString("i"));
+            Check.equal(7L, config.getLong("missing", 7));
+        });
+
+        Check.test("Config environment overrides match keys without case", () -> {
+            Config config = Config.parse(FIXTURE);
+            Map<String, String> env = new HashMap<String, String>();
+            env.put("BRUNA_NAV__PID__ALTITUDE__KP", "2.0");
+            env.put("BRUNA_NET__MAXPEERS", "16");
+            env.put("BRUNA_NET__RETRYLIMIT", "3");
+            env.put("BRUNA_NEW__KEY", "not a number");
+            env.put("OTHER", "ignored");
+            Config.Schema schema = new Config.Schema().optional("net.retryLimit", 5);
+            config.applyEnv(env, schema);
+            Check.near(2.0, config.getDouble("nav.pid.altitude.kp"), 0);
+            Check.equal(16, config.getInt("net.maxPeers"));
+            Check.equal(3L, config.getLong("net.retryLimit"));
+            Check.equal("not a number", config.getString("new.key"));
+            Check.isFalse(config.contains("net.maxpeers"), "no lower-case duplicate");
+            Check.equal(Collections.emptyList(), config.validate(schema));
+            Check.equal(3L, config.getLong("net.retryLimit"));
+        });
+
+        Check.test("Config validation lists every problem and fills defaults", () -> {
+            Config config = Config.parse("net.port = \"x\"");
+            Config.Schema schema = new Config.Schema()
+                    .require("platform", String.class)
+                    .require("net.port", Long.class)
+                    .optional("net.timeout", 2.5)
+                    .optional("net.note", null);
+            List<String> problems = config.validate(schema);
+            Check.equal(2, problems.size());
+            Check.equal("platform: required key is missing", problems.get(0));
+            Check.isTrue(problems.get(1).startsWith("net.port: expected integer"), problems.get(1));
+            Check.near(2.5, config.getDouble("net.timeout"), 0);
+            Check.isFalse(config.contains("net.note"), "null default adds nothing");
+            config.set("net.note", true);
+            config.set("net.port", 14550);
+            config.set("platform", "sim");
+            Check.equal(Collections.emptyList(), config.validate(schema));
+        });
+
+        Check.test("Config serialize round trips", () -> {
+            Config config = Config.parse(FIXTURE);
+            Check.equal(config.asMap(), Config.parse(config.serialize()).asMap());
+        });
+    }
+
+    public static void main(String[] args){
+        run();
+        Check.exit();
+    }
+}
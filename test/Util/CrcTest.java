@@ -0,0 +1,54 @@
+package Util;
+
+import java.nio.charset.StandardCharsets;
+
+public class CrcTest {
+    private static final byte[] CHECK = "123456789".getBytes(StandardCharsets.US_ASCII);
+
+    public static void run(){
+        Check.test("Crc8 check value", () -> {
+            Check.equal(0xF4, Crc.Crc8.compute(CHECK));
+            Check.equal(0xF4, Crc.Crc8.computeBitwise(CHECK));
+        });
+
+        Check.test("Crc16Ccitt check value", () -> {
+            Check.equal(0x29B1, Crc.Crc16Ccitt.compute(CHECK));
+            Check.equal(0x29B1, Crc.Crc16Ccitt.computeBitwise(CHECK));
+        });
+
+        Check.test("Crc16Mcrf4xx check value", () -> {
+            Check.equal(0x6F91, Crc.Crc16Mcrf4xx.compute(CHECK));
+            Check.equal(0x6F91, Crc.Crc16Mcrf4xx.computeBitwise(CHECK));
+        });
+
+        Check.test("Crc16X25 check value", () -> {
+            Check.equal(0x906E, Crc.Crc16X25.compute(CHECK));
+            Check.equal(0x906E, Crc.Crc16X25.computeBitwise(CHECK));
+        });
+
+        Check.test("Crc32 check value", () -> {
+            Check.equal(0xCBF43926L, Crc.Crc32.compute(CHECK));
+            Check.equal(0xCBF43926L, Crc.Crc32.computeBitwise(CHECK));
+        });
+
+        Check.test("NmeaXor check value", () -> Check.equal(0x31, Crc.NmeaXor.compute(CHECK)));
+
+        Check.test("Fletcher16 check value", () -> Check.equal(0x1EDE, Crc.Fletcher16.compute(CHECK)));
+
+        Check.test("Crc streaming matches one-shot", () -> {
+            Crc.Crc16Mcrf4xx crc = new Crc.Crc16Mcrf4xx();
+            crc.update(CHECK, 0, 4).update(CHECK, 4, 5);
+            Check.equal(0x6F91, crc.finish());
+            crc.reset();
+            for (byte b : CHECK){
+                crc.update(b);
+            }
+            Check.equal(0x6F91, crc.finish());
+        });
+    }
+
+    public static void main(String[] args){
+        run();
+        Check.exit();
+    }
+}
@@ -0,0 +1,77 @@
+package Util;
+
+import Util.Enums.ObligationState;
+
+import java.util.Arrays;
+import java.util.Collections;
+
+public class DeadlineSupervisorTest {
+    public static void run(){
+        Check.test("DeadlineSupervisor fires once per expiry episode", () -> {
+            DeadlineSupervisor supervisor = new DeadlineSupervisor();
+            int[] fired = {0};
+            supervisor.register("rc", 100, () -> fired[0]++, 0);
+            Check.equal(Collections.emptyList(), supervisor.tick(99));
+            Check.equal(Arrays.asList("rc"), supervisor.tick(100));
+            Check.equal(Collections.emptyList(), supervisor.tick(500));
+            Check.equal(1, fired[0]);
+            Check.equal(ObligationState.EXPIRED, supervisor.status().get("rc").getState());
+        });
+
+        Check.test("DeadlineSupervisor re-arms after a kick", () -> {
+            DeadlineSupervisor supervisor = new DeadlineSupervisor();
+            int[] fired = {0};
+            supervisor.register("rc", 100, () -> fired[0]++, 0);
+            supervisor.tick(100);
+            supervisor.kick("rc", 150);
+            Check.equal(ObligationState.OK, supervisor.status().get("rc").getState());
+            Check.equal(Collections.emptyList(), supervisor.tick(249));
+            Check.equal(Arrays.asList("rc"), supervisor.tick(250));
+            Check.equal(2L, supervisor.status().get("rc").getExpiries());
+        });
+
+        Check.test("DeadlineSupervisor pause and resume", () -> {
+            DeadlineSupervisor supervisor = new DeadlineSupervisor();
+            supervisor.register("esc", 100, () -> {}, 0);
+            supervisor.pause("esc");
+            Check.equal(Collections.emptyList(), supervisor.tick(10000));
+            Check.equal(ObligationState.PAUSED, supervisor.status().get("esc").getState());
+            supervisor.resume("esc", 10000);
+            Check.equal(Collections.emptyList(), supervisor.tick(10099));
+            Check.equal(Arrays.asList("esc"), supervisor.tick(10100));
+        });
+
+        Check.test("DeadlineSupervisor allows a reentrant kick from an action", () -> {
+            DeadlineSupervisor supervisor = new DeadlineSupervisor();
+            supervisor.register("link", 100, () -> supervisor.kick("link", 100), 0);
+            Check.equal(Arrays.asList("link"), supervisor.tick(100));
+            Check.equal(ObligationState.OK, supervisor.status().get("link").getState());
+            Check.equal(Arrays.asList("link"), supervisor.tick(200));
+        });
+
+        Check.test("DeadlineSupervisor keeps running actions after one throws", () -> {
+            DeadlineSupervisor supervisor = new DeadlineSupervisor();
+            int[] fired = {0};
+            supervisor.register("a", 10, () -> { throw new IllegalStateException("boom");}, 0);
+            supervisor.register("b", 10, () -> fired[0]++, 0);
+            supervisor.register("c", 10, () -> { throw new UnsupportedOperationException("boom");}, 0);
+            supervisor.register("d", 10, () -> fired[0]++, 0);
+            Check.equal(Arrays.asList("a", "b", "c", "d"), supervisor.tick(10));
+            Check.equal(2, fired[0]);
+            Check.equal(2L, supervisor.getActionFailures());
+        });
+
+        Check.test("DeadlineSupervisor lets Errors from actions propagate", () -> {
+            DeadlineSupervisor supervisor = new DeadlineSupervisor();
+            supervisor.register("a", 10, () -> { throw new AssertionError("boom");}, 0);
+            Check.throwsA(AssertionError.class, () -> supervisor.tick(10));
+            Check.equal(0L, supervisor.getActionFailures());
+            Check.equal(ObligationState.EXPIRED, supervisor.status().get("a").getState());
+        });
+    }
+
+    public static void main(String[] args){
+        run();
+        Check.exit();
+    }
+}
@@ -0,0 +1,100 @@
+package Util;
+
+import java.util.ArrayList;
+import java.util.Arrays;
+import java.util.Collections;
+import java.util.List;
+
+public class EventBusTest {
+    private static class BatteryLow {
+        final int percent;
+
+        BatteryLow(int percent){
+            this.percent = percent;
+        }
+    }
+
+    private static class ModeChanged {}
+
+    public static void run(){
+        Check.test("EventBus delivers in subscription order by exact type", () -> {
+            EventBus bus = new EventBus();
+            List<String> log = new ArrayList<String>();
+            bus.subscribe(BatteryLow.class, e -> log.add("a" + e.percent));
+            bus.subscribe(BatteryLow.class, e -> log.add("b" + e.percent));
+            bus.subscribe(ModeChanged.class, e -> log.add("mode"));
+            bus.publish(new BatteryLow(15));
+            Check.equal(Arrays.asList("a15", "b15"), log);
+            Check.equal(2L, bus.getDelivered());
+        });
+
+        Check.test("EventBus unsubscribe during dispatch", () -> {
+            EventBus bus = new EventBus();
+            List<String> log = new ArrayList<String>();
+            List<EventBus.Subscription<BatteryLow>> subs = new ArrayList<EventBus.Subscription<BatteryLow>>();
+            subs.add(bus.subscribe(BatteryLow.class, e -> {
+                log.add("first");
+                subs.get(1).close();
+                bus.subscribe(BatteryLow.class, x -> log.add("late"));
+            }));
+            subs.add(bus.subscribe(BatteryLow.class, e -> log.add("second")));
+            bus.publish(new BatteryLow(10));
+            Check.equal(Arrays.asList("first"), log);
+            Check.isFalse(subs.get(1).isActive(), "closed");
+            subs.get(0).close();
+            subs.get(0).close();
+            bus.publish(new BatteryLow(9));
+            Check.equal(Arrays.asList("first", "late"), log);
+            Check.equal(1, bus.listenerCount(BatteryLow.class));
+        });
+
+        Check.test("EventBus queues reentrant publishes", () -> {
+            EventBus bus = new EventBus();
+            List<String> log = new ArrayList<String>();
+            bus.subscribe(BatteryLow.class, e -> {
+                log.add("battery start");
+                bus.publish(new ModeChanged());
+                log.add("battery end");
+            });
+            bus.subscribe(ModeChanged.class, e -> log.add("mode"));
+            bus.publish(new BatteryLow(5));
+            Check.equal(Arrays.asList("battery start", "battery end", "mode"), log);
+        });
+
+        Check.test("EventBus isolates listeners that throw", () -> {
+            EventBus bus = new EventBus();
+            List<String> log = new ArrayList<String>();
+            bus.subscribe(BatteryLow.class, e -> { throw new IllegalStateException("boom");});
+            bus.subscribe(BatteryLow.class, e -> log.add("ok"));
+            bus.publish(new BatteryLow(1));
+            bus.publish(new BatteryLow(2));
+            Check.equal(Arrays.asList("ok", "ok"), log);
+            Check.equal(2L, bus.getFailures());
+            Check.equal(2L, bus.getDelivered());
+        });
+
+        Check.test("EventBus lets Errors propagate and stays usable", () -> {
+            EventBus bus = new EventBus();
+            List<String> log = new ArrayList<String>();
+            boolean[] fail = {true};
+            bus.subscribe(BatteryLow.class, e -> {
+                bus.publish(new ModeChanged());
+                if (fail[0]){
+                    throw new AssertionError("boom");
+                }
+            });
+            bus.subscribe(ModeChanged.class, e -> log.add("mode"));
+            Check.throwsA(AssertionError.class, () -> bus.publish(new BatteryLow(1)));
+            Check.equal(0L, bus.getFailures());
+            Check.equal(Collections.emptyList(), log);
+            fail[0] = false;
+            bus.publish(new BatteryLow(2));
+            Check.equal(Arrays.asList("mode"), log);
+        });
+    }
+
+    public static void main(String[] args){
+        run();
+        Check.exit();
+    }
+}
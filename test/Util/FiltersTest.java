@@ -0,0 +1,117 @@
+package Util;
+
+import Util.Enums.Edge;
+
+public class FiltersTest {
+    public static void run(){
+        Check.test("MovingAverage step response", () -> {
+            Filters.MovingAverage avg = new Filters.MovingAverage(4);
+            Check.near(0, avg.update(0), 0);
+            Check.near(0.5, avg.update(1), 1e-12);
+            Check.near(2.0 / 3, avg.update(1), 1e-12);
+            Check.near(0.75, avg.update(1), 1e-12);
+            Check.near(1, avg.update(1), 1e-12);
+            Check.near(1, avg.update(1), 1e-12);
+        });
+
+        Check.test("LowPass step response matches 1 - (1 - alpha)^n", () -> {
+            Filters.LowPass lp = new Filters.LowPass(1, 0.01);
+            double alpha = 0.01 / (1 / (2 * Math.PI) + 0.01);
+            Check.near(alpha, lp.getAlpha(), 1e-12);
+            lp.update(0);
+            double y = 0;
+            for (int n = 1; n <= 50; n++){
+                y = lp.update(1);
+                Check.near(1 - Math.pow(1 - alpha, n), y, 1e-12);
+            }
+        });
+
+        Check.test("LowPass impulse response decays geometrically", () -> {
+            Filters.LowPass lp = new Filters.LowPass(5, 0.02);
+            double alpha = lp.getAlpha();
+            lp.update(0);
+            Check.near(alpha, lp.update(1), 1e-12);
+            for (int n = 1; n <= 10; n++){
+                Check.near(alpha * Math.pow(1 - alpha, n), lp.update(0), 1e-12);
+            }
+        });
+
+        Check.test("MedianFilter rejects outliers", () -> {
+            Filters.MedianFilter median = new Filters.MedianFilter(5);
+            double[] samples = {10, 10, 1000, 10, 11, -500, 10, 12};
+            double out = 0;
+            for (double s : samples){
+                out = median.update(s);
+            }
+            Check.near(10, out, 0);
+            Check.near(10, median.update(10), 0);
+            Check.throwsA(IllegalArgumentException.class, () -> new Filters.MedianFilter(4));
+        });
+
+        Check.test("MedianFilter partial window", () -> {
+            Filters.MedianFilter median = new Filters.MedianFilter(5);
+            Check.near(3, median.update(3), 0);
+            Check.near(2, median.update(1), 0);
+            Check.near(3, median.update(7), 0);
+        });
+
+        Check.test("RateOfChange ramp and smoothing", () -> {
+            Filters.RateOfChange raw = new Filters.RateOfChange(0.1, 1);
+            Check.near(0, raw.update(0), 0);
+            Check.near(20, raw.update(2), 1e-9);
+            Check.near(20, raw.update(4), 1e-9);
+            Filters.RateOfChange smooth = new Filters.RateOfChange(0.1, 0.5);
+            smooth.update(0);
+            Check.near(10, smooth.update(2), 1e-9);
+            Check.near(15, smooth.update(4), 1e-9);
+        });
+
+        Check.test("Hysteresis reports edges only on switching", () -> {
+            Filters.Hysteresis h = new Filters.Hysteresis(10, 5);
+            Check.equal(Edge.NONE, h.update(9));
+            Check.equal(Edge.RISING, h.update(10));
+            Check.equal(Edge.NONE, h.update(6));
+            Check.equal(Edge.NONE, h.update(12));
+            Check.equal(Edge.FALLING, h.update(5));
+            Check.equal(Edge.NONE, h.update(9.9));
+            Check.isFalse(h.isHigh(), "low");
+            h.reset();
+            Check.isFalse(h.isHigh(), "initial state");
+        });
+
+        Check.test("Filters reject NaN and infinity without touching state", () -> {
+            Filters.MovingAverage avg = new Filters.MovingAverage(3);
+            Check.isTrue(Double.isNaN(avg.update(Double.NaN)), "no output yet");
+            avg.update(3);
+            Check.near(3, avg.update(Double.NaN), 0);
+            Check.near(3, avg.update(Double.POSITIVE_INFINITY), 0);
+            Check.near(2, avg.update(1), 0);
+            Check.equal(3L, avg.getRejected());
+
+            Filters.LowPass lp = new Filters.LowPass(1, 0.1);
+            lp.update(4);
+            Check.near(4, lp.update(Double.NaN), 0);
+            Check.equal(1L, lp.getRejected());
+
+            Filters.MedianFilter median = new Filters.MedianFilter(3);
+            median.update(1);
+            Check.near(1, median.update(Double.NaN), 0);
+            Check.near(1.5, median.update(2), 0);
+
+            Filters.RateOfChange rate = new Filters.RateOfChange(1, 1);
+            rate.update(0);
+            rate.update(Double.NaN);
+            Check.near(1, rate.update(1), 0);
+
+            Filters.Hysteresis h = new Filters.Hysteresis(1, 0, true);
+            Check.equal(Edge.NONE, h.update(Double.NaN));
+            Check.isTrue(h.isHigh(), "state kept");
+            Check.equal(1L, h.getRejected());
+        });
+    }
+
+    public static void main(String[] args){
+        run();
+        Check.exit();
+    }
+}
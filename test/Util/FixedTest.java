@@ -0,0 +1,83 @@
+package Util;
+
+import java.util.Random;
+
+public class FixedTest {
+    public static void run(){
+        Check.test("Fixed arithmetic matches doubles within one unit", () -> {
+            Random rng = new Random(3);
+            double ulp = 1.0 / (1 << Fixed.Q16);
+            for (int i = 0; i < 10000; i++){
+                double a = (rng.nextDouble() - 0.5) * 200;
+                double b = (rng.nextDouble() - 0.5) * 200;
+                Fixed fa = Fixed.q16(a);
+                Fixed fb = Fixed.q16(b);
+                double ea = fa.toDouble();
+                double eb = fb.toDouble();
+                Check.near(ea + eb, fa.add(fb).toDouble(), ulp);
+                Check.near(ea - eb, fa.sub(fb).toDouble(), ulp);
+                Check.near(ea * eb, fa.mul(fb).toDouble(), ulp);
+                if (Math.abs(eb) > 0.01){
+                    Check.near(ea / eb, fa.div(fb).toDouble(), ulp);
+                }
+            }
+        });
+
+        Check.test("Fixed overflow throws, saturates or returns null", () -> {
+            Fixed big = Fixed.fromInt(30000, Fixed.Q16);
+            Fixed min = Fixed.fromInt(-30000, Fixed.Q16);
+            Check.throwsA(ArithmeticException.class, () -> big.add(big));
+            Check.throwsA(ArithmeticException.class, () -> big.mul(big));
+            Check.equal(Integer.MAX_VALUE, big.saturatingAdd(big).getRaw());
+            Check.equal(Integer.MIN_VALUE, min.saturatingSub(big).getRaw());
+            Check.equal(Integer.MAX_VALUE, big.saturatingMul(big).getRaw());
+            Check.equal(null, big.checkedAdd(big));
+            Check.equal(null, min.checkedSub(big));
+            Check.equal(null, big.checkedMul(big));
+            Check.equal(null, big.checkedDiv(Fixed.q16(0.001)));
+            Check.equal(null, big.checkedDiv(Fixed.zero(Fixed.Q16)));
+            Check.equal(Fixed.fromInt(-2, Fixed.Q16), Fixed.fromInt(1, Fixed.Q16).checkedSub(Fixed.fromInt(3, Fixed.Q16)));
+            Check.equal(Fixed.q16(0.5), Fixed.fromInt(1, Fixed.Q16).checkedDiv(Fixed.fromInt(2, Fixed.Q16)));
+            Check.throwsA(ArithmeticException.class, () -> big.div(Fixed.zero(Fixed.Q16)));
+        });
+
+        Check.test("Fixed sqrt and toInt", () -> {
+            Check.near(Math.sqrt(2), Fixed.fromInt(2, Fixed.Q16).sqrt().toDouble(), 1.0 / 65536);
+            Check.equal(-2, Fixed.q16(-2.75).toInt());
+            Check.equal(2, Fixed.q16(2.75).toInt());
+            Check.throwsA(ArithmeticException.class, () -> Fixed.q16(-1).sqrt());
+        });
+
+        Check.test("Fixed sin and cos match Math within one unit for all formats", () -> {
+            int[] formats = {8, 12, Fixed.Q16, 24, 28};
+            for (int f : formats){
+                double ulp = 1.0 / (1L << f);
+                double range = Math.min(1000, (1L << (30 - f)));
+                for (int i = -2000; i <= 2000; i++){
+                    Fixed angle = Fixed.fromFloat(range * i / 2000.0, f);
+                    double exact = angle.toDouble();
+                    Check.near(Math.sin(exact), angle.sin().toDouble(), ulp + 1e-8);
+                    Check.near(Math.cos(exact), angle.cos().toDouble(), ulp + 1e-8);
+                }
+            }
+        });
+
+        Check.test("Fixed trig at quadrant boundaries and extreme angles", () -> {
+            Fixed halfPi = Fixed.halfPi(Fixed.Q16);
+            Check.near(1, halfPi.sin().toDouble(), 2.0 / 65536);
+            Check.near(0, halfPi.cos().toDouble(), 2.0 / 65536);
+            Check.near(1, Fixed.halfPi(30).sin().toDouble(), 1e-8);
+            Fixed huge = Fixed.fromRaw(Integer.MAX_VALUE, Fixed.Q16);
+            Check.near(Math.sin(huge.toDouble()), huge.sin().toDouble(), 2.0 / 65536);
+            Fixed low = Fixed.fromRaw(Integer.MIN_VALUE, 30);
+            Check.near(Math.cos(low.toDouble()), low.cos().toDouble(), 1e-8);
+            Fixed whole = Fixed.fromRaw(Integer.MAX_VALUE, 0);
+            Check.equal(Math.round(Math.sin(whole.toDouble())), (long) whole.sin().getRaw());
+        });
+    }
+
+    public static void main(String[] args){
+        run();
+        Check.exit();
+    }
+}
@@ -0,0 +1,85 @@
+package Util;
+
+import java.util.ArrayList;
+import java.util.Arrays;
+import java.util.List;
+
+public class FlagSetTest {
+    // Declared out of bit order so iteration order is checked against bits.
+    enum Perm implements Flag {
+        EXEC(4), READ(1), WRITE(2);
+
+        private final int bit;
+
+        Perm(int bit){
+            this.bit = bit;
+        }
+
+        public int bit(){ return bit;}
+    }
+
+    enum Clashing implements Flag {
+        A, B;
+
+        public int bit(){ return 1;}
+    }
+
+    public static void run(){
+        Check.test("FlagSet set algebra", () -> {
+            FlagSet<Perm> rw = FlagSet.of(Perm.class, Perm.READ, Perm.WRITE);
+            FlagSet<Perm> wx = FlagSet.of(Perm.class, Perm.WRITE, Perm.EXEC);
+            Check.equal(FlagSet.all(Perm.class), rw.union(wx));
+            Check.equal(FlagSet.of(Perm.class, Perm.WRITE), rw.intersection(wx));
+            Check.equal(FlagSet.of(Perm.class, Perm.READ), rw.difference(wx));
+            Check.isTrue(FlagSet.all(Perm.class).containsAll(rw), "all contains rw");
+            Check.isFalse(rw.containsAll(wx), "rw lacks exec");
+            Check.equal(2, rw.size());
+            Check.isTrue(FlagSet.empty(Perm.class).isEmpty(), "empty");
+            Check.equal(3, rw.getBits());
+        });
+
+        Check.test("FlagSet insert and remove report changes", () -> {
+            FlagSet<Perm> set = FlagSet.empty(Perm.class);
+            Check.isTrue(set.insert(Perm.EXEC), "added");
+            Check.isFalse(set.insert(Perm.EXEC), "already present");
+            Check.isTrue(set.contains(Perm.EXEC), "contains exec");
+            FlagSet<Perm> copy = set.copy();
+            Check.isTrue(set.remove(Perm.EXEC), "removed");
+            Check.isFalse(set.remove(Perm.EXEC), "already gone");
+            Check.isTrue(copy.contains(Perm.EXEC), "copy is independent");
+        });
+
+        Check.test("FlagSet iterates in bit order", () -> {
+            List<Perm> seen = new ArrayList<Perm>();
+            for (Perm p : FlagSet.all(Perm.class)){
+                seen.add(p);
+            }
+            Check.equal(Arrays.asList(Perm.READ, Perm.WRITE, Perm.EXEC), seen);
+            Check.equal(Arrays.asList("READ", "EXEC"), FlagSet.of(Perm.class, Perm.EXEC, Perm.READ).names());
+        });
+
+        Check.test("FlagSet display formatting", () -> {
+            Check.equal("{}", FlagSet.empty(Perm.class).toString());
+            Check.equal("{WRITE}", FlagSet.of(Perm.class, Perm.WRITE).toString());
+            Check.equal("{READ | WRITE | EXEC}", FlagSet.all(Perm.class).toString());
+        });
+
+        Check.test("FlagSet round trips through bits and names", () -> {
+            FlagSet<Perm> set = FlagSet.of(Perm.class, Perm.READ, Perm.EXEC);
+            Check.equal(set, FlagSet.fromBits(Perm.class, set.getBits()));
+            Check.equal(set, FlagSet.fromNames(Perm.class, set.names()));
+            Check.throwsA(IllegalArgumentException.class, () -> FlagSet.fromBits(Perm.class, 8));
+            Check.equal(FlagSet.of(Perm.class, Perm.READ), FlagSet.fromBitsTruncate(Perm.class, 9));
+            Check.throwsA(IllegalArgumentException.class, () -> FlagSet.fromNames(Perm.class, Arrays.asList("DELETE")));
+        });
+
+        Check.test("FlagSet rejects flags sharing a bit", () -> {
+            Check.throwsA(IllegalStateException.class, () -> FlagSet.all(Clashing.class));
+        });
+    }
+
+    public static void main(String[] args){
+        run();
+        Check.exit();
+    }
+}
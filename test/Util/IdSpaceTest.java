@@ -0,0 +1,76 @@
+package Util;
+
+import java.util.HashSet;
+import java.util.Set;
+
+public class IdSpaceTest {
+    public static void run(){
+        Check.test("IdSpace with a zero prefix counts from one", () -> {
+            IdSpace ids = new IdSpace();
+            Check.equal(1L, ids.next());
+            Check.equal(2L, ids.next());
+            Check.equal(3L, ids.next());
+        });
+
+        Check.test("IdSpace ids are unique across nodes", () -> {
+            Set<Long> seen = new HashSet<Long>();
+            for (long node = 0; node < 4; node++){
+                IdSpace ids = new IdSpace(node);
+                for (int i = 0; i < 100; i++){
+                    Check.isTrue(seen.add(ids.next()), "duplicate id");
+                }
+            }
+            Check.equal(400, seen.size());
+        });
+
+        Check.test("IdSpace extraction round trips", () -> {
+            IdSpace ids = new IdSpace(0x1234, 16);
+            ids.next();
+            long id = ids.next();
+            Check.equal(0x12340002L, id);
+            Check.equal(0x1234L, ids.nodeOf(id));
+            Check.equal(2L, ids.localOf(id));
+            Check.isTrue(ids.owns(id), "own id");
+            Check.isFalse(ids.owns(new IdSpace(7, 16).next()), "other node's id");
+            Check.isFalse(ids.owns(0x12340000L), "local id 0 is never handed out");
+            IdSpace top = new IdSpace((1L << 16) - 1);
+            long high = top.next();
+            Check.isTrue(high < 0, "ids use the full unsigned range");
+            Check.equal((1L << 16) - 1, top.nodeOf(high));
+        });
+
+        Check.test("IdSpace instances do not share counters", () -> {
+            IdSpace a = new IdSpace(1);
+            IdSpace b = new IdSpace(1);
+            a.next();
+            a.next();
+            Check.equal(1L, b.localOf(b.next()));
+            Check.equal(3L, a.localOf(a.next()));
+        });
+
+        Check.test("IdSpace reports exhaustion", () -> {
+            IdSpace ids = new IdSpace(1, 2);
+            Check.equal(3L, ids.remaining());
+            ids.next();
+            ids.next();
+            Check.isFalse(ids.isExhausted(), "one id left");
+            Check.equal(7L, ids.next());
+            Check.isTrue(ids.isExhausted(), "no ids left");
+            Check.equal(0L, ids.remaining());
+            Check.throwsA(IllegalStateException.class, ids::next);
+            Check.throwsA(IllegalStateException.class, ids::next);
+        });
+
+        Check.test("IdSpace rejects prefixes that do not fit", () -> {
+            Check.throwsA(IllegalArgumentException.class, () -> new IdSpace(1L << 16, 48));
+            Check.throwsA(IllegalArgumentException.class, () -> new IdSpace(-1));
+            Check.throwsA(IllegalArgumentException.class, () -> new IdSpace(0, 0));
+            Check.throwsA(IllegalArgumentException.class, () -> new IdSpace(0, 64));
+        });
+    }
+
+    public static void main(String[] args){
+        run();
+        Check.exit();
+    }
+}
@@ -0,0 +1,126 @@
+package Util;
+
+import java.util.ArrayList;
+import java.util.List;
+import java.util.Random;
+
+public class IndexedPriorityQueueTest {
+    public static void run(){
+        Check.test("IndexedPriorityQueue breaks ties by insertion order", () -> {
+            IndexedPriorityQueue<String, Integer> q = new IndexedPriorityQueue<String, Integer>();
+            q.push("a", 2);
+            q.push("b", 1);
+            q.push("c", 2);
+            q.push("d", 1);
+            Check.equal("b", q.pop().getKey());
+            Check.equal("d", q.pop().getKey());
+            Check.equal("a", q.pop().getKey());
+            Check.equal("c", q.pop().getKey());
+            Check.equal(null, q.pop());
+        });
+
+        Check.test("IndexedPriorityQueue update raises and lowers priority", () -> {
+            IndexedPriorityQueue<String, Integer> q = new IndexedPriorityQueue<String, Integer>();
+            q.push("a", 1);
+            q.push("b", 5);
+            q.push("c", 3);
+            Check.isTrue(q.updatePriority("b", 0), "b queued");
+            Check.equal("b", q.peek().getKey());
+            Check.isTrue(q.updatePriority("b", 9), "b queued");
+            Check.equal("a", q.pop().getKey());
+            Check.equal("c", q.pop().getKey());
+            Check.equal(9, q.pop().getPriority());
+            Check.isFalse(q.updatePriority("b", 1), "b gone");
+        });
+
+        Check.test("IndexedPriorityQueue update keeps insertion order, push refreshes it", () -> {
+            IndexedPriorityQueue<String, Integer> q = new IndexedPriorityQueue<String, Integer>();
+            q.push("a", 5);
+            q.push("b", 1);
+            q.updatePriority("a", 1);
+            Check.equal("a", q.peek().getKey());
+            Check.isFalse(q.push("a", 1), "a already queued");
+            Check.equal("b", q.peek().getKey());
+        });
+
+        Check.test("IndexedPriorityQueue removes from the middle", () -> {
+            IndexedPriorityQueue<Integer, Integer> q = new IndexedPriorityQueue<Integer, Integer>();
+            for (int i = 0; i < 10; i++){
+                q.push(i, i);
+            }
+            Check.equal(5, q.remove(5));
+            Check.equal(null, q.remove(5));
+            Check.isFalse(q.contains(5), "5 removed");
+            Check.equal(9, q.size());
+            for (int i = 0; i < 10; i++){
+                if (i != 5){
+                    Check.equal(i, q.pop().getKey());
+                }
+            }
+            Check.isTrue(q.isEmpty(), "drained");
+        });
+
+        Check.test("IndexedPriorityQueue matches a naive reference", () -> {
+            Random random = new Random(11);
+            IndexedPriorityQueue<Integer, Integer> q = new IndexedPriorityQueue<Integer, Integer>();
+            List<long[]> ref = new ArrayList<long[]>();
+            long seq = 0;
+            for (int step = 0; step < 5000; step++){
+                int key = random.nextInt(50);
+                int prio = random.nextInt(20);
+                long[] entry = find(ref, key);
+                switch (random.nextInt(4)){
+                    case 0:
+                        Check.equal(entry == null, q.push(key, prio));
+                        if (entry == null){
+                            ref.add(new long[]{key, prio, seq++});
+                        } else {
+                            entry[1] = prio;
+                            entry[2] = seq++;
+                        }
+                        break;
+                    case 1:
+                        Check.equal(entry != null, q.updatePriority(key, prio));
+                        if (entry != null){
+                            entry[1] = prio;
+                        }
+                        break;
+                    case 2:
+                        Check.equal(entry == null ? null : (int) entry[1], q.remove(key));
+                        ref.remove(entry);
+                        break;
+                    default:
+                        long[] min = null;
+                        for (long[] e : ref){
+                            if (min == null || e[1] < min[1] || (e[1] == min[1] && e[2] < min[2])){
+                                min = e;
+                            }
+                        }
+                        IndexedPriorityQueue.Node<Integer, Integer> node = q.pop();
+                        if (min == null){
+                            Check.equal(null, node);
+                        } else {
+                            Check.equal((int) min[0], node.getKey());
+                            Check.equal((int) min[1], node.getPriority());
+                            ref.remove(min);
+                        }
+                }
+                Check.equal(ref.size(), q.size());
+            }
+        });
+    }
+
+    private static long[] find(List<long[]> ref, int key){
+        for (long[] e : ref){
+            if (e[0] == key){
+                return e;
+            }
+        }
+        return null;
+    }
+
+    public static void main(String[] args){
+        run();
+        Check.exit();
+    }
+}
@@ -0,0 +1,99 @@
+package Util;
+
+import java.util.Random;
+
+public class KalmanTest {
+    public static void run(){
+        Check.test("Kalman1D converges on a noisy constant with the steady-state variance", () -> {
+            Random rng = new Random(1);
+            double q = 0.01;
+            double r = 1;
+            Kalman.Kalman1D k = new Kalman.Kalman1D(0, 100, q, r);
+            for (int i = 0; i < 2000; i++){
+                k.predict();
+                k.update(5 + rng.nextGaussian());
+            }
+            // Riccati fixed point of p = (p + q) r / (p + q + r).
+            double expected = (-q + Math.sqrt(q * q + 4 * q * r)) / 2;
+            Check.near(expected, k.getVariance(), 1e-9);
+            Check.near(5, k.getState(), 0.3);
+        });
+
+        Check.test("Kalman1D gate skips outliers and counts them", () -> {
+            Kalman.Kalman1D k = new Kalman.Kalman1D(0, 1, 0.01, 1);
+            k.setGate(3);
+            for (int i = 0; i < 50; i++){
+                k.predict();
+                k.update(0);
+            }
+            double before = k.getState();
+            k.predict();
+            Check.isFalse(k.update(100), "outlier accepted");
+            Check.equal(1L, k.getGated());
+            Check.equal(before, k.getState());
+            Check.isTrue(k.update(0.5), "inlier rejected");
+        });
+
+        Check.test("KalmanN tracks a noisy ramp and keeps P positive definite", () -> {
+            Random rng = new Random(2);
+            double dt = 0.1;
+            Kalman.KalmanN k = constantVelocity(dt);
+            boolean positive = true;
+            for (int i = 0; i < 5000; i++){
+                k.predict();
+                k.update(new double[]{2 * i * dt + rng.nextGaussian() * 0.5});
+                double[][] p = k.getCovariance();
+                positive &= p[0][0] > 0 && p[1][1] > 0 && p[0][0] * p[1][1] - p[0][1] * p[1][0] > 0;
+                positive &= p[0][1] == p[1][0];
+            }
+            Check.isTrue(positive, "covariance lost positive definiteness");
+            Check.near(2 * 4999 * dt, k.getState()[0], 1);
+            Check.near(2, k.getState()[1], 0.05);
+        });
+
+        Check.test("KalmanN reduces to the scalar filter", () -> {
+            Kalman.Kalman1D scalar = new Kalman.Kalman1D(0, 10, 0.5, 2);
+            Kalman.KalmanN matrix = new Kalman.KalmanN(1, 1);
+            matrix.setCovariance(new double[][]{{10}});
+            matrix.setProcessNoise(new double[][]{{0.5}});
+            matrix.setObservation(new double[][]{{1}});
+            matrix.setMeasurementNoise(new double[][]{{2}});
+            double[] zs = {1, 3, 2, 5, 4};
+            for (double z : zs){
+                scalar.predict();
+                scalar.update(z);
+                matrix.predict();
+                matrix.update(new double[]{z});
+            }
+            Check.near(scalar.getState(), matrix.getState()[0], 1e-12);
+            Check.near(scalar.getVariance(), matrix.getCovariance()[0][0], 1e-12);
+        });
+
+        Check.test("KalmanN gate uses the Mahalanobis distance", () -> {
+            Kalman.KalmanN k = constantVelocity(0.1);
+            k.setGate(4);
+            for (int i = 0; i < 100; i++){
+                k.predict();
+                k.update(new double[]{0});
+            }
+            k.predict();
+            Check.isFalse(k.update(new double[]{1e4}), "outlier accepted");
+            Check.equal(1L, k.getGated());
+        });
+    }
+
+    private static Kalman.KalmanN constantVelocity(double dt){
+        Kalman.KalmanN k = new Kalman.KalmanN(2, 1);
+        k.setTransition(new double[][]{{1, dt}, {0, 1}});
+        k.setProcessNoise(new double[][]{{1e-6, 0}, {0, 1e-6}});
+        k.setObservation(new double[][]{{1, 0}});
+        k.setMeasurementNoise(new double[][]{{0.25}});
+        k.setCovariance(new double[][]{{100, 0}, {0, 100}});
+        return k;
+    }
+
+    public static void main(String[] args){
+        run();
+        Check.exit();
+    }
+}
@@ -0,0 +1,86 @@
+package Util.Logging;
+
+import Util.Check;
+import Util.Enums.LogLevel;
+
+import java.util.List;
+import java.util.Map;
+
+public class LoggerTest {
+    private static Logger logger(LogSink... sinks){
+        Logger logger = new Logger();
+        logger.setClock(() -> 42);
+        for (LogSink sink : sinks){
+            logger.addSink(sink);
+        }
+        return logger;
+    }
+
+    public static void run(){
+        Check.test("Logger filters by level and most specific target", () -> {
+            RingSink ring = new RingSink(16);
+            Logger logger = logger(ring);
+            logger.setLevel(LogLevel.WARN);
+            logger.setLevel("comms", LogLevel.DEBUG);
+            logger.setLevel("comms.mavlink", LogLevel.ERROR);
+            logger.info("nav", "dropped");
+            logger.debug("comms.radio", "kept");
+            logger.warn("comms.mavlink", "dropped");
+            logger.error("comms.mavlink", "kept");
+            logger.warn("nav", "kept");
+            Check.equal(3, ring.getEntries().size());
+            for (LogEntry entry : ring.getEntries()){
+                Check.equal("kept", entry.getMessage());
+            }
+        });
+
+        Check.test("Logger captures fields in order", () -> {
+            RingSink ring = new RingSink(4);
+            logger(ring).info("nav", "waypoint reached", "index", 3, "error_m", 0.4);
+            LogEntry entry = ring.getEntries().get(0);
+            Check.equal(42L, entry.getTimestampMs());
+            List<Map.Entry<String, Object>> fields = entry.getFields();
+            Check.equal(2, fields.size());
+            Check.equal("index", fields.get(0).getKey());
+            Check.equal(3, fields.get(0).getValue());
+            Check.equal("error_m", fields.get(1).getKey());
+            Check.equal("42 INFO nav: waypoint reached index=3 error_m=0.4", entry.toString());
+        });
+
+        Check.test("Logger keeps an unpaired trailing value", () -> {
+            RingSink ring = new RingSink(4);
+            logger(ring).info("nav", "odd", "index", 3, "orphan");
+            List<Map.Entry<String, Object>> fields = ring.getEntries().get(0).getFields();
+            Check.equal(2, fields.size());
+            Check.equal(Logger.UNPAIRED_KEY, fields.get(1).getKey());
+            Check.equal("orphan", fields.get(1).getValue());
+        });
+
+        Check.test("Logger fans out and survives a failing sink", () -> {
+            RingSink first = new RingSink(4);
+            RingSink second = new RingSink(4);
+            Logger logger = logger(first, entry -> { throw new IllegalStateException("disk full");}, second);
+            logger.info("nav", "hello");
+            Check.equal(1, first.getEntries().size());
+            Check.equal(1, second.getEntries().size());
+            Check.equal(1L, logger.getSinkFailures());
+        });
+
+        Check.test("RingSink wraps and rejects a non-positive capacity", () -> {
+            RingSink ring = new RingSink(2);
+            Logger logger = logger(ring);
+            logger.info("nav", "a");
+            logger.info("nav", "b");
+            logger.info("nav", "c");
+            Check.equal("b", ring.getEntries().get(0).getMessage());
+            Check.equal("c", ring.getEntries().get(1).getMessage());
+            Check.equal(1L, ring.getOverwritten());
+            Check.throwsA(IllegalArgumentException.class, () -> new RingSink(0));
+        });
+    }
+
+    public static void main(String[] args){
+        run();
+        Check.exit();
+    }
+}
@@ -0,0 +1,63 @@
+package Util.Logging;
+
+import Util.Check;
+import Util.Enums.LogLevel;
+
+import java.util.Collections;
+import java.util.List;
+import java.util.Map;
+
+public class SuppressingSinkTest {
+    private static LogEntry entry(long nowMs, String message){
+        return new LogEntry(nowMs, LogLevel.WARN, "radio", message, Collections.<Map.Entry<String, Object>>emptyList());
+    }
+
+    public static void run(){
+        Check.test("SuppressingSink reports suppressed repeats", () -> {
+            RingSink ring = new RingSink(16);
+            SuppressingSink sink = new SuppressingSink(ring, 1000);
+            for (int i = 0; i < 5; i++){
+                sink.write(entry(i * 10, "link lost"));
+            }
+            Check.equal(1, ring.getEntries().size());
+            Check.equal(4L, sink.getTotalSuppressed());
+            sink.write(entry(1000, "link lost"));
+            List<LogEntry> entries = ring.getEntries();
+            Check.equal(3, entries.size());
+            Check.equal("4 similar messages suppressed", entries.get(1).getMessage());
+            Check.equal("link lost", entries.get(2).getMessage());
+        });
+
+        Check.test("SuppressingSink flush reports quiet messages", () -> {
+            RingSink ring = new RingSink(16);
+            SuppressingSink sink = new SuppressingSink(ring, 1000);
+            sink.write(entry(0, "gps glitch"));
+            sink.write(entry(10, "gps glitch"));
+            sink.flush(500);
+            Check.equal(1, ring.getEntries().size());
+            sink.flush(1010);
+            Check.equal("1 similar messages suppressed", ring.getEntries().get(1).getMessage());
+            Check.equal(0, sink.getTracked());
+        });
+
+        Check.test("SuppressingSink evicts quiet keys on write", () -> {
+            RingSink ring = new RingSink(64);
+            SuppressingSink sink = new SuppressingSink(ring, 1000);
+            for (int i = 0; i < 20; i++){
+                sink.write(entry(i, "message " + i));
+            }
+            sink.write(entry(5, "message 0"));
+            Check.equal(20, sink.getTracked());
+            sink.write(entry(2000, "fresh"));
+            Check.equal(1, sink.getTracked());
+            List<LogEntry> entries = ring.getEntries();
+            Check.equal("1 similar messages suppressed", entries.get(20).getMessage());
+            Check.equal("fresh", entries.get(entries.size() - 1).getMessage());
+        });
+    }
+
+    public static void main(String[] args){
+        run();
+        Check.exit();
+    }
+}
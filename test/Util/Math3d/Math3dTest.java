@@ -0,0 +1,102 @@
+package Util.Math3d;
+
+import Util.Check;
+
+public class Math3dTest {
+    private static final float EPS = 1e-5f;
+    private static final float HALF_PI = (float) (Math.PI / 2);
+
+    public static void run(){
+        Check.test("Vec3 cross product is right-handed", () -> {
+            Check.isTrue(Vec3.UNIT_X.cross(Vec3.UNIT_Y).approxEq(Vec3.UNIT_Z, EPS), "x cross y");
+            Check.isTrue(Vec3.UNIT_Y.cross(Vec3.UNIT_Z).approxEq(Vec3.UNIT_X, EPS), "y cross z");
+            Check.isTrue(Vec3.UNIT_Y.cross(Vec3.UNIT_X).approxEq(Vec3.UNIT_Z.scale(-1), EPS), "y cross x");
+            Check.near(0, new Vec3(1, 2, 3).dot(new Vec3(1, 2, 3).cross(new Vec3(-4, 5, 6))), EPS);
+        });
+
+        Check.test("Vec3 normalization handles degenerate input", () -> {
+            Check.near(1, new Vec3(3, 4, 12).normalize().norm(), EPS);
+            Check.isTrue(new Vec3(3, 4, 0).normalize().approxEq(new Vec3(0.6f, 0.8f, 0), EPS), "3-4-5");
+            Check.equal(Vec3.ZERO, Vec3.ZERO.normalize());
+            Check.equal(Vec3.ZERO, new Vec3(Float.NaN, 0, 0).normalize());
+            Check.equal(Vec3.ZERO, new Vec3(Float.POSITIVE_INFINITY, 0, 0).normalize());
+        });
+
+        Check.test("Quat normalization handles degenerate input", () -> {
+            Check.equal(Quat.IDENTITY, new Quat(0, 0, 0, 0).normalize());
+            Check.equal(Quat.IDENTITY, new Quat(Float.NaN, 0, 0, 0).normalize());
+            Check.near(1, new Quat(2, 1, -1, 3).normalize().norm(), EPS);
+            Check.equal(Quat.IDENTITY, Quat.fromAxisAngle(Vec3.ZERO, 1));
+        });
+
+        Check.test("Identity rotations leave vectors alone", () -> {
+            Vec3 v = new Vec3(1, -2, 3);
+            Check.isTrue(Quat.IDENTITY.rotate(v).approxEq(v, EPS), "quat");
+            Check.isTrue(Mat3.IDENTITY.multiply(v).approxEq(v, EPS), "mat");
+            Check.isTrue(Quat.IDENTITY.toMat3().approxEq(Mat3.IDENTITY, EPS), "quat to mat");
+            Check.isTrue(Quat.fromEuler(0, 0, 0).approxEq(Quat.IDENTITY, EPS), "euler");
+        });
+
+        Check.test("Known rotations", () -> {
+            Quat yaw = Quat.fromEuler(0, 0, HALF_PI);
+            Check.isTrue(yaw.rotate(Vec3.UNIT_X).approxEq(Vec3.UNIT_Y, EPS), "yaw 90 turns x into y");
+            Quat roll = Quat.fromEuler(HALF_PI, 0, 0);
+            Check.isTrue(roll.rotate(Vec3.UNIT_Y).approxEq(Vec3.UNIT_Z, EPS), "roll 90 turns y into z");
+            Quat pitch = Quat.fromEuler(0, HALF_PI, 0);
+            Check.isTrue(pitch.rotate(Vec3.UNIT_Z).approxEq(Vec3.UNIT_X, EPS), "pitch 90 turns z into x");
+            Check.isTrue(Quat.fromAxisAngle(Vec3.UNIT_Z, HALF_PI).approxEq(yaw, EPS), "axis-angle matches euler");
+            Mat3 m = yaw.toMat3();
+            Check.near(-1, m.get(0, 1), EPS);
+            Check.near(1, m.get(1, 0), EPS);
+        });
+
+        Check.test("Euler, quaternion and matrix conversions agree", () -> {
+            float[][] angles = {{0.1f, -0.4f, 2.5f}, {-1.2f, 0.7f, -3.0f}, {3.0f, 1.2f, 0.3f}};
+            Vec3 v = new Vec3(0.3f, -1.7f, 2.2f);
+            for (float[] a : angles){
+                Quat q = Quat.fromEuler(a[0], a[1], a[2]);
+                float[] back = q.toEuler();
+                for (int i = 0; i < 3; i++){
+                    Check.near(a[i], back[i], 1e-4);
+                }
+                Check.isTrue(q.rotate(v).approxEq(q.toMat3().multiply(v), 1e-4f), "quat and matrix rotate alike");
+                Mat3 r = q.toMat3();
+                Check.isTrue(r.multiply(r.transpose()).approxEq(Mat3.IDENTITY, 1e-5f), "rotation is orthonormal");
+                Check.isTrue(q.multiply(q.conjugate()).approxEq(Quat.IDENTITY, EPS), "conjugate inverts");
+            }
+        });
+
+        Check.test("Euler conversion at gimbal lock", () -> {
+            Quat q = Quat.fromEuler(0.4f, HALF_PI, 0.9f);
+            float[] e = q.toEuler();
+            Check.near(0, e[0], EPS);
+            Check.near(HALF_PI, e[1], 1e-3);
+            Vec3 v = new Vec3(1, 2, 3);
+            Quat back = Quat.fromEuler(e[0], e[1], e[2]);
+            Check.isTrue(back.rotate(v).approxEq(q.rotate(v), 1e-3f), "same rotation after round trip");
+        });
+
+        Check.test("Quat composition matches matrix product", () -> {
+            Quat a = Quat.fromEuler(0.3f, 0.2f, -0.5f);
+            Quat b = Quat.fromAxisAngle(new Vec3(1, 1, 0), 0.8f);
+            Check.isTrue(a.multiply(b).toMat3().approxEq(a.toMat3().multiply(b.toMat3()), 1e-5f), "composition");
+        });
+
+        Check.test("Slerp endpoints and midpoint", () -> {
+            Quat a = Quat.IDENTITY;
+            Quat b = Quat.fromAxisAngle(Vec3.UNIT_Z, HALF_PI);
+            Check.isTrue(Quat.slerp(a, b, 0).approxEq(a, EPS), "t = 0");
+            Check.isTrue(Quat.slerp(a, b, 1).approxEq(b, EPS), "t = 1");
+            Quat mid = Quat.slerp(a, b, 0.5f);
+            Check.isTrue(mid.approxEq(Quat.fromAxisAngle(Vec3.UNIT_Z, HALF_PI / 2), EPS), "midpoint");
+            Check.near(1, mid.norm(), EPS);
+            Quat negated = new Quat(-b.w, -b.x, -b.y, -b.z);
+            Check.isTrue(Quat.slerp(a, negated, 0.5f).approxEq(mid, EPS), "takes the short way");
+        });
+    }
+
+    public static void main(String[] args){
+        run();
+        Check.exit();
+    }
+}
@@ -0,0 +1,65 @@
+package Util.Rate;
+
+import Util.Check;
+
+public class RateTest {
+    public static void run(){
+        Check.test("TokenBucket refills at its rate up to capacity", () -> {
+            TokenBucket bucket = new TokenBucket(10, 5, 0);
+            Check.isTrue(bucket.tryConsume(10, 0), "starts full");
+            Check.isFalse(bucket.tryConsume(1, 0), "empty");
+            Check.isFalse(bucket.tryConsume(1, 199), "0.995 tokens");
+            Check.isTrue(bucket.tryConsume(1, 200), "one token after 200 ms");
+            Check.near(10, bucket.available(100000), 1e-9);
+        });
+
+        Check.test("SlidingWindowCounter at bucket boundaries", () -> {
+            SlidingWindowCounter counter = new SlidingWindowCounter(1000, 10);
+            counter.record(0);
+            counter.record(99);
+            counter.record(100);
+            Check.equal(3L, counter.count(999));
+            Check.equal(1L, counter.count(1000));
+            Check.equal(1L, counter.count(1099));
+            Check.equal(0L, counter.count(1100));
+            counter.record(5, 5000);
+            Check.near(5.0, counter.rate(5000), 1e-9);
+            Check.equal(0L, counter.count(60000));
+        });
+
+        Check.test("Throttle emits first then once per interval", () -> {
+            Throttle throttle = new Throttle(100);
+            Check.isTrue(throttle.tryEmit(0), "first passes");
+            Check.isFalse(throttle.tryEmit(50), "inside interval");
+            Check.isFalse(throttle.tryEmit(99), "inside interval");
+            Check.isTrue(throttle.tryEmit(100), "interval elapsed");
+            Check.equal(2L, throttle.takeSuppressed());
+            Check.equal(0L, throttle.getSuppressed());
+        });
+
+        Check.test("Debouncer emits last value after quiet time", () -> {
+            Debouncer<String> debouncer = new Debouncer<String>(100);
+            debouncer.submit("a", 0);
+            debouncer.submit("b", 50);
+            Check.equal(null, debouncer.poll(149));
+            Check.equal("b", debouncer.poll(150));
+            Check.equal(null, debouncer.poll(1000));
+            Check.isFalse(debouncer.isPending(), "burst consumed");
+        });
+
+        Check.test("Debouncer keeps a submitted null", () -> {
+            Debouncer<String> debouncer = new Debouncer<String>(100);
+            debouncer.submit(null, 0);
+            Check.isTrue(debouncer.isPending(), "null is pending");
+            Check.isFalse(debouncer.isReady(99), "not settled");
+            Check.isTrue(debouncer.isReady(100), "settled");
+            Check.equal(null, debouncer.poll(100));
+            Check.isFalse(debouncer.isPending(), "released");
+        });
+    }
+
+    public static void main(String[] args){
+        run();
+        Check.exit();
+    }
+}
@@ -0,0 +1,101 @@
+package Util;
+
+import Util.Enums.OverflowPolicy;
+
+import java.nio.ByteBuffer;
+import java.util.ArrayList;
+import java.util.Arrays;
+import java.util.List;
+
+public class RingBufferTest {
+    private static List<Integer> contents(RingBuffer<Integer> ring){
+        List<Integer> out = new ArrayList<Integer>();
+        for (Integer value : ring){
+            out.add(value);
+        }
+        return out;
+    }
+
+    public static void run(){
+        Check.test("RingBuffer wraps around in order", () -> {
+            RingBuffer<Integer> ring = new RingBuffer<Integer>(3, OverflowPolicy.REJECT);
+            ring.push(1);
+            ring.push(2);
+            Check.equal(1, ring.pop());
+            ring.push(3);
+            ring.push(4);
+            Check.equal(Arrays.asList(2, 3, 4), contents(ring));
+            Check.equal(2, ring.peek());
+            Check.isTrue(ring.isFull(), "full");
+        });
+
+        Check.test("RingBuffer overflow policies", () -> {
+            RingBuffer<Integer> reject = new RingBuffer<Integer>(2, OverflowPolicy.REJECT);
+            Check.isTrue(reject.push(1) && reject.push(2), "accepted while room");
+            Check.isFalse(reject.push(3), "rejected when full");
+            Check.equal(Arrays.asList(1, 2), contents(reject));
+            RingBuffer<Integer> overwrite = new RingBuffer<Integer>(2, OverflowPolicy.OVERWRITE);
+            overwrite.push(1);
+            overwrite.push(2);
+            Check.isTrue(overwrite.push(3), "overwrite accepts");
+            Check.equal(Arrays.asList(2, 3), contents(overwrite));
+        });
+
+        Check.test("Rings reject a non-positive capacity", () -> {
+            Check.throwsA(IllegalArgumentException.class, () -> new RingBuffer<Integer>(0, OverflowPolicy.REJECT));
+            Check.throwsA(IllegalArgumentException.class, () -> new ByteRing(0));
+            Check.throwsA(IllegalArgumentException.class, () -> new ByteRing(-4));
+            Check.throwsA(IllegalArgumentException.class, () -> new SpscRing<Integer>(0));
+        });
+
+        Check.test("ByteRing partial IO across the wrap", () -> {
+            ByteRing ring = new ByteRing(8);
+            Check.equal(6, ring.write(new byte[]{1, 2, 3, 4, 5, 6}));
+            byte[] buf = new byte[4];
+            Check.equal(4, ring.read(buf));
+            Check.equal(6, ring.write(new byte[]{7, 8, 9, 10, 11, 12, 13}));
+            Check.equal(0, ring.free());
+            ByteBuffer[] slices = ring.asSlices();
+            Check.equal(4, slices[0].remaining());
+            Check.equal(4, slices[1].remaining());
+            Check.equal(5, (int) slices[0].get(0));
+            Check.equal(9, (int) slices[1].get(0));
+            byte[] all = new byte[16];
+            Check.equal(8, ring.read(all));
+            Check.isTrue(Arrays.equals(new byte[]{5, 6, 7, 8, 9, 10, 11, 12}, Arrays.copyOf(all, 8)), "bytes in order");
+            Check.equal(-1, ring.read());
+            Check.throwsA(IllegalArgumentException.class, () -> ring.consume(1));
+            Check.throwsA(IllegalArgumentException.class, () -> ring.consume(-1));
+        });
+
+        Check.test("SpscRing two-thread stress keeps sequence", () -> {
+            SpscRing<Integer> ring = new SpscRing<Integer>(64);
+            int count = 200000;
+            Thread producer = new Thread(() -> {
+                for (int i = 0; i < count; i++){
+                    while (!ring.producer().offer(i)){
+                        Thread.yield();
+                    }
+                }
+            });
+            producer.start();
+            int expected = 0;
+            while (expected < count){
+                Integer value = ring.consumer().poll();
+                if (value == null){
+                    Thread.yield();
+                    continue;
+                }
+                Check.equal(expected, value);
+                expected++;
+            }
+            producer.join();
+            Check.equal(null, ring.consumer().poll());
+        });
+    }
+
+    public static void main(String[] args){
+        run();
+        Check.exit();
+    }
+}
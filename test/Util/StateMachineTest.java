@@ -0,0 +1,85 @@
+package Util;
+
+import Util.Enums.TransitionResult;
+
+import java.util.ArrayList;
+import java.util.Arrays;
+import java.util.List;
+
+public class StateMachineTest {
+    // AIRBORNE holds CRUISE and LOITER; GROUND and FLIGHT hold nothing.
+    private static StateMachine.Builder<String, String, List<String>> builder(String initial){
+        StateMachine.Builder<String, String, List<String>> builder = new StateMachine.Builder<String, String, List<String>>(initial)
+                .parent("CRUISE", "AIRBORNE")
+                .parent("LOITER", "AIRBORNE");
+        for (String state : Arrays.asList("GROUND", "AIRBORNE", "CRUISE", "LOITER")){
+            builder.onEntry(state, log -> log.add("enter " + state));
+            builder.onExit(state, log -> log.add("exit " + state));
+        }
+        return builder;
+    }
+
+    public static void run(){
+        Check.test("StateMachine child to parent does not re-enter the parent", () -> {
+            StateMachine<String, String, List<String>> sm = builder("CRUISE")
+                    .transition("CRUISE", "hold", "AIRBORNE").build();
+            List<String> log = new ArrayList<String>();
+            Check.equal(TransitionResult.ACCEPTED, sm.handle("hold", log));
+            Check.equal(Arrays.asList("exit CRUISE"), log);
+            Check.equal("AIRBORNE", sm.getState());
+        });
+
+        Check.test("StateMachine parent to child does not exit the parent", () -> {
+            StateMachine<String, String, List<String>> sm = builder("AIRBORNE")
+                    .transition("AIRBORNE", "go", "CRUISE").build();
+            List<String> log = new ArrayList<String>();
+            sm.handle("go", log);
+            Check.equal(Arrays.asList("enter CRUISE"), log);
+            Check.isTrue(sm.isIn("AIRBORNE"), "still in AIRBORNE");
+        });
+
+        Check.test("StateMachine sibling transition keeps the parent", () -> {
+            StateMachine<String, String, List<String>> sm = builder("CRUISE")
+                    .transition("CRUISE", "loiter", "LOITER", null, log -> log.add("action")).build();
+            List<String> log = new ArrayList<String>();
+            sm.handle("loiter", log);
+            Check.equal(Arrays.asList("exit CRUISE", "action", "enter LOITER"), log);
+        });
+
+        Check.test("StateMachine leaving the hierarchy exits child then parent", () -> {
+            StateMachine<String, String, List<String>> sm = builder("LOITER")
+                    .transition("AIRBORNE", "land", "GROUND").build();
+            List<String> log = new ArrayList<String>();
+            Check.equal(TransitionResult.ACCEPTED, sm.handle("land", log));
+            Check.equal(Arrays.asList("exit LOITER", "exit AIRBORNE", "enter GROUND"), log);
+            log.clear();
+            sm = builder("GROUND").transition("GROUND", "launch", "CRUISE").build();
+            sm.handle("launch", log);
+            Check.equal(Arrays.asList("exit GROUND", "enter AIRBORNE", "enter CRUISE"), log);
+        });
+
+        Check.test("StateMachine self transition exits and re-enters", () -> {
+            StateMachine<String, String, List<String>> sm = builder("CRUISE")
+                    .transition("CRUISE", "reset", "CRUISE").build();
+            List<String> log = new ArrayList<String>();
+            sm.handle("reset", log);
+            Check.equal(Arrays.asList("exit CRUISE", "enter CRUISE"), log);
+        });
+
+        Check.test("StateMachine guards and unknown events", () -> {
+            StateMachine<String, String, List<String>> sm = builder("GROUND")
+                    .transition("GROUND", "launch", "CRUISE", log -> false, null).build();
+            List<String> log = new ArrayList<String>();
+            Check.equal(TransitionResult.REJECTED_BY_GUARD, sm.handle("launch", log));
+            Check.equal(TransitionResult.NO_TRANSITION, sm.handle("land", log));
+            Check.isTrue(log.isEmpty(), "no actions ran");
+            Check.equal("GROUND", sm.getState());
+            Check.equal(2, sm.getHistory().size());
+        });
+    }
+
+    public static void main(String[] args){
+        run();
+        Check.exit();
+    }
+}
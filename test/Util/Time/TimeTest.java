@@ -0,0 +1,71 @@
+package Util.Time;
+
+import Util.Check;
+
+import java.time.Duration;
+
+public class TimeTest {
+    public static void run(){
+        Check.test("TickRate converts whole ticks exactly", () -> {
+            TickRate rate = new TickRate(1000);
+            Check.equal(Duration.ofMillis(1), rate.tickLength());
+            Check.equal(Duration.ofMillis(2500), rate.toDuration(new Ticks(2500)));
+            Check.equal(new Ticks(2500), rate.ticksMs(2500));
+            Check.equal(new Ticks(2500), rate.ticks(rate.toDuration(new Ticks(2500))));
+        });
+
+        Check.test("TickRate rounding rules", () -> {
+            TickRate rate = new TickRate(3);
+            Check.equal(Duration.ofNanos(333333333), rate.toDuration(new Ticks(1)));
+            Check.equal(Duration.ofNanos(666666667), rate.toDuration(new Ticks(2)));
+            Check.equal(Duration.ofSeconds(1), rate.toDuration(new Ticks(3)));
+            Check.equal(new Ticks(2), rate.ticksMs(999));
+            Check.equal(new Ticks(3), rate.ticksCeil(Duration.ofMillis(999)));
+            Check.equal(new Ticks(3), rate.ticksCeil(Duration.ofSeconds(1)));
+            Check.equal(Ticks.ZERO, rate.ticksCeil(Duration.ZERO));
+        });
+
+        Check.test("TickRate rejects bad rates and durations", () -> {
+            Check.throwsA(IllegalArgumentException.class, () -> new TickRate(0));
+            Check.throwsA(IllegalArgumentException.class, () -> new TickRate(1000000001L));
+            Check.throwsA(IllegalArgumentException.class, () -> new TickRate(100).ticks(Duration.ofMillis(-1)));
+            Check.throwsA(ArithmeticException.class, () -> new TickRate(1000).ticks(Duration.ofSeconds(Long.MAX_VALUE / 10)));
+        });
+
+        Check.test("Ticks arithmetic overflow behaviour", () -> {
+            Ticks max = new Ticks(Long.MAX_VALUE);
+            Check.equal(null, max.checkedAdd(new Ticks(1)));
+            Check.equal(max, max.checkedAdd(Ticks.ZERO));
+            Check.throwsA(ArithmeticException.class, () -> max.add(new Ticks(1)));
+            Check.equal(Ticks.ZERO, new Ticks(3).saturatingSub(new Ticks(5)));
+            Check.equal(new Ticks(2), new Ticks(5).saturatingSub(new Ticks(3)));
+            Check.throwsA(IllegalArgumentException.class, () -> new Ticks(-1));
+        });
+
+        Check.test("Timestamp arithmetic", () -> {
+            Timestamp t = Timestamp.now(() -> 1000L);
+            Check.equal(new Timestamp(1000), t);
+            Check.equal(new Timestamp(1500), t.checkedAdd(Duration.ofNanos(500)));
+            Check.equal(null, new Timestamp(Long.MAX_VALUE - 1).checkedAdd(Duration.ofNanos(2)));
+            Check.equal(Duration.ofNanos(400), new Timestamp(1400).since(t));
+            Check.equal(Duration.ZERO, t.since(new Timestamp(1400)));
+            Check.isTrue(t.isBefore(new Timestamp(1001)), "earlier");
+        });
+
+        Check.test("Duration formatting fixtures", () -> {
+            Check.equal("1h02m03.450s", Durations.formatMs(3723450));
+            Check.equal("2m03.450s", Durations.formatMs(123450));
+            Check.equal("3.450s", Durations.formatMs(3450));
+            Check.equal("0.000s", Durations.formatMs(0));
+            Check.equal("-1.500s", Durations.formatMs(-1500));
+            Check.equal("25h00m00.000s", Durations.format(Duration.ofHours(25)));
+            Check.equal("0.001s", Durations.format(Duration.ofNanos(1999999)));
+            Check.equal("T+1.000s", new Timestamp(1000000000L).toString());
+        });
+    }
+
+    public static void main(String[] args){
+        run();
+        Check.exit();
+    }
+}
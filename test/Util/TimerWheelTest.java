@@ -0,0 +1,51 @@
+package Util;
+
+import java.util.ArrayList;
+import java.util.Arrays;
+import java.util.Collections;
+import java.util.List;
+import java.util.Random;
+
+public class TimerWheelTest {
+    public static void run(){
+        Check.test("TimerWheel expires many timers in deadline order", () -> {
+            TimerWheel<Integer> wheel = new TimerWheel<Integer>();
+            List<Integer> ids = new ArrayList<Integer>();
+            for (int i = 0; i < 1000; i++){
+                ids.add(i);
+            }
+            Collections.shuffle(ids, new Random(3));
+            for (int id : ids){
+                wheel.schedule(id, id * 10L);
+            }
+            List<Integer> due = wheel.expire(4990);
+            Check.equal(500, due.size());
+            for (int i = 0; i < due.size(); i++){
+                Check.equal(i, due.get(i));
+            }
+            Check.equal(500, wheel.size());
+            Check.equal(5000L, wheel.nextDeadline());
+            Check.equal(Collections.emptyList(), wheel.expire(4999));
+        });
+
+        Check.test("TimerWheel reschedule, cancel and ties", () -> {
+            TimerWheel<String> wheel = new TimerWheel<String>();
+            wheel.schedule("a", 100);
+            wheel.schedule("b", 100);
+            wheel.schedule("c", 50);
+            wheel.schedule("c", 200);
+            Check.isTrue(wheel.cancel("b"), "cancelled");
+            Check.isFalse(wheel.cancel("b"), "already gone");
+            wheel.schedule("d", 100);
+            Check.equal(Arrays.asList("a", "d"), wheel.expire(150));
+            Check.isTrue(wheel.contains("c"), "c still pending");
+            Check.equal(Arrays.asList("c"), wheel.expire(200));
+            Check.equal(null, wheel.nextDeadline());
+        });
+    }
+
+    public static void main(String[] args){
+        run();
+        Check.exit();
+    }
+}
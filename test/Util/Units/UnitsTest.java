@@ -0,0 +1,63 @@
+package Util.Units;
+
+import Util.Check;
+
+public class UnitsTest {
+    public static void run(){
+        Check.test("Degree and radian conversions are exact", () -> {
+            Check.equal(Radians.of(Math.PI), Degrees.of(180).toRadians());
+            Check.equal(Degrees.of(90), Radians.of(Math.PI / 2).toDegrees());
+            Check.near(-45, Degrees.of(-45).toRadians().toDegrees().getValue(), 1e-12);
+            Check.near(-170, Degrees.of(190).normalized().getValue(), 1e-12);
+            Check.near(-180, Degrees.of(180).normalized().getValue(), 1e-12);
+            Check.near(-Math.PI / 2, Radians.of(3 * Math.PI / 2).normalized().getValue(), 1e-12);
+        });
+
+        Check.test("Percent is validated to 0..100", () -> {
+            Check.equal(50.0, Percent.of(50).getValue());
+            Check.equal(Percent.FULL, Percent.of(100));
+            Check.equal(Percent.of(25), Percent.fromFraction(0.25));
+            Check.near(0.4, Percent.of(40).toFraction(), 1e-12);
+            Check.throwsA(IllegalArgumentException.class, () -> Percent.of(-0.1));
+            Check.throwsA(IllegalArgumentException.class, () -> Percent.of(100.5));
+            Check.throwsA(IllegalArgumentException.class, () -> Percent.fromFraction(1.01));
+            Check.equal(Percent.FULL, Percent.clamped(130));
+            Check.equal(Percent.ZERO, Percent.clamped(-5));
+            Check.throwsA(IllegalArgumentException.class, () -> Percent.clamped(Double.NaN));
+        });
+
+        Check.test("Quantities reject non-finite and out-of-range values", () -> {
+            Check.throwsA(IllegalArgumentException.class, () -> Meters.of(Double.NaN));
+            Check.throwsA(IllegalArgumentException.class, () -> Volts.of(Double.POSITIVE_INFINITY));
+            Check.throwsA(IllegalArgumentException.class, () -> Meters.of(10).per(0));
+            Check.throwsA(IllegalArgumentException.class, () -> Celsius.of(-300));
+            Check.throwsA(IllegalArgumentException.class, () -> Celsius.fromKelvin(-1));
+        });
+
+        Check.test("Arithmetic helpers", () -> {
+            Check.equal(Meters.of(7), Meters.of(3).add(Meters.of(4)));
+            Check.equal(Meters.of(-1), Meters.of(3).sub(Meters.of(4)));
+            Check.equal(MetersPerSec.of(2.5), Meters.of(10).per(4));
+            Check.equal(Meters.of(10), MetersPerSec.of(2.5).over(4));
+            Check.equal(Amps.of(3), Amps.of(1.5).scale(2));
+            Check.near(24, Volts.of(12).watts(Amps.of(2)), 1e-12);
+            Check.near(300.15, Celsius.of(27).toKelvin(), 1e-9);
+            Check.near(5, Celsius.of(25).minus(Celsius.of(20)), 1e-12);
+            Check.equal(Celsius.of(21), Celsius.of(20).plus(1));
+        });
+
+        Check.test("Equality, ordering and display", () -> {
+            Check.isFalse(Meters.of(1).equals(MetersPerSec.of(1)), "different units are never equal");
+            Check.isTrue(Meters.of(1).compareTo(Meters.of(2)) < 0, "ordering");
+            Check.equal(Meters.of(1).hashCode(), Meters.of(1).hashCode());
+            Check.equal("1.5 m", Meters.of(1.5).toString());
+            Check.equal("3.0 m/s", MetersPerSec.of(3).toString());
+            Check.equal("50.0 %", Percent.of(50).toString());
+        });
+    }
+
+    public static void main(String[] args){
+        run();
+        Check.exit();
+    }
+}
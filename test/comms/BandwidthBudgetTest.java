@@ -0,0 +1,88 @@
+package comms;
+
+import Util.Check;
+
+public class BandwidthBudgetTest {
+    public static void run(){
+        Check.test("BandwidthBudget splits by weight and starts full", () -> {
+            BandwidthBudget budget = new BandwidthBudget(1000, 1.0);
+            budget.register("telemetry", 3, 0);
+            budget.register("bulk", 1, 0);
+            Check.near(750, budget.shareOf("telemetry"), 1e-9);
+            Check.near(250, budget.shareOf("bulk"), 1e-9);
+            Check.isTrue(budget.tryConsume("bulk", 250, 0), "new consumer has its burst");
+            Check.isTrue(budget.tryConsume("telemetry", 375, 0), "first consumer also full");
+        });
+
+        Check.test("BandwidthBudget absorbs bursts up to capacity", () -> {
+            BandwidthBudget budget = new BandwidthBudget(100, 2.0, 1.0);
+            budget.register("a", 1, 0);
+            Check.isTrue(budget.tryConsume("a", 200, 0), "two seconds of burst");
+            Check.isFalse(budget.tryConsume("a", 1, 0), "spent");
+            Check.isTrue(budget.tryConsume("a", 50, 500), "refilled 50 bytes");
+        });
+
+        Check.test("BandwidthBudget borrows above the lender's reserve", () -> {
+            BandwidthBudget budget = new BandwidthBudget(200, 1.0);
+            budget.register("telemetry", 1, 0);
+            budget.register("bulk", 1, 0);
+            Check.isTrue(budget.tryConsume("bulk", 100, 0), "own share");
+            Check.isTrue(budget.tryConsume("telemetry", 40, 0), "lender partly used");
+            Check.isTrue(budget.tryConsume("bulk", 10, 0), "borrowed from surplus of 10");
+            Check.equal(10L, budget.getBorrowedBytes("bulk"));
+            Check.isFalse(budget.tryConsume("bulk", 1, 0), "reserve is not lent");
+        });
+
+        Check.test("BandwidthBudget records deferral and runtime reduction", () -> {
+            BandwidthBudget budget = new BandwidthBudget(1000, 1.0, 1.0);
+            budget.register("telemetry", 1, 0);
+            budget.register("bulk", 1, 0);
+            budget.tryConsume("bulk", 500, 0);
+            budget.tryConsume("telemetry", 500, 0);
+            budget.setTotal(100, 0);
+            Check.near(50, budget.shareOf("bulk"), 1e-9);
+            Check.isFalse(budget.tryConsume("bulk", 50, 500), "only 25 bytes earned");
+            Check.isTrue(budget.tryConsume("bulk", 50, 1000), "50 bytes after a second");
+            Check.equal(500L, budget.getDeferredMs("bulk"));
+        });
+
+        Check.test("QosQueue holds over-budget frames until the budget refills", () -> {
+            BandwidthBudget budget = new BandwidthBudget(100, 1.0, 1.0);
+            budget.register(QosClass.BULK.name(), 1, 0);
+            QosQueue queue = new QosQueue(8, 1000);
+            queue.setBudget(budget);
+            queue.enqueue(QosClass.BULK, new byte[80], 0);
+            queue.enqueue(QosClass.BULK, new byte[80], 0);
+            queue.enqueue(QosClass.TELEMETRY, new byte[10], 0);
+            Check.equal(QosClass.TELEMETRY, queue.poll(0).getQos());
+            Check.equal(QosClass.BULK, queue.poll(0).getQos());
+            Check.equal(null, queue.poll(0));
+            Check.equal(1, queue.size(QosClass.BULK));
+            Check.equal(null, queue.poll(500));
+            QosQueue.Frame released = queue.poll(600);
+            Check.equal(80, released.getData().length);
+            Check.isTrue(queue.isEmpty(), "released");
+            Check.equal(2L, queue.getStats(QosClass.BULK).getDeferred());
+            Check.equal(600L, budget.getDeferredMs(QosClass.BULK.name()));
+        });
+
+        Check.test("QosQueue sends a lower class while a higher one is over budget", () -> {
+            BandwidthBudget budget = new BandwidthBudget(10, 1.0, 1.0);
+            budget.register(QosClass.TELEMETRY.name(), 1, 0);
+            Check.isTrue(budget.tryConsume(QosClass.TELEMETRY.name(), 10, 0), "spend the burst");
+            QosQueue queue = new QosQueue(8, 1000);
+            queue.setBudget(budget);
+            queue.enqueue(QosClass.TELEMETRY, new byte[20], 0);
+            queue.enqueue(QosClass.BULK, new byte[20], 0);
+            Check.equal(QosClass.BULK, queue.poll(0).getQos());
+            Check.equal(1, queue.size(QosClass.TELEMETRY));
+            Check.equal(null, queue.poll(500));
+            Check.equal(QosClass.TELEMETRY, queue.poll(1000).getQos());
+        });
+    }
+
+    public static void main(String[] args){
+        run();
+        Check.exit();
+    }
+}
@@ -0,0 +1,134 @@
+package comms;
+
+import Util.Check;
+
+import java.nio.charset.StandardCharsets;
+import java.util.Arrays;
+import java.util.Random;
+
+public class CompressionTest {
+    private static byte[] telemetry(){
+        StringBuilder sb = new StringBuilder();
+        for (int i = 0; i < 200; i++){
+            sb.append("alt=").append(100 + i % 3).append(" mode=HOLD status=OK;");
+        }
+        return sb.toString().getBytes(StandardCharsets.US_ASCII);
+    }
+
+    private static byte[] noise(int size){
+        byte[] data = new byte[size];
+        new Random(7).nextBytes(data);
+        return data;
+    }
+
+    public static void run(){
+        Check.test("Compression round trips every algorithm", () -> {
+            byte[][] inputs = {new byte[0], {42}, telemetry(), noise(5000), new byte[1000]};
+            for (Compression algo : Compression.values()){
+                for (byte[] input : inputs){
+                    byte[] packed = Compression.compress(input, algo);
+                    Check.isTrue(Arrays.equals(input, Compression.decompress(packed, algo, input.length)), algo + " " + input.length);
+                }
+            }
+        });
+
+        Check.test("Compression shrinks compressible data", () -> {
+            byte[] data = telemetry();
+            Check.isTrue(Compression.compress(data, Compression.LZ77).length < data.length / 3, "lz77 ratio");
+            Check.equal(8, Compression.compress(new byte[1000], Compression.RLE).length);
+        });
+
+        Check.test("CompressionPolicy skips small or larger results", () -> {
+            CompressionPolicy policy = new CompressionPolicy(Compression.LZ77, 64);
+            Check.equal(Compression.NONE, policy.encode("log", new byte[32]).getAlgo());
+            Check.equal(Compression.NONE, policy.encode("log", noise(2000)).getAlgo());
+            CompressionPolicy.Encoded encoded = policy.encode("log", telemetry());
+            Check.equal(Compression.LZ77, encoded.getAlgo());
+            CompressionPolicy.Stats stats = policy.getStats("log");
+            Check.equal(2L, stats.getSkipped());
+            Check.equal(1L, stats.getCompressed());
+            Check.isTrue(stats.getRatio() < 1.0, "overall ratio below one");
+            Check.near(1.0, policy.getStats("other").getRatio(), 0);
+        });
+
+        Check.test("Compression header flags are stable", () -> {
+            Check.equal(0, Compression.NONE.getFlag());
+            Check.equal(1, Compression.RLE.getFlag());
+            Check.equal(2, Compression.LZ77.getFlag());
+            for (Compression algo : Compression.values()){
+                Check.equal(algo, Compression.fromFlag(algo.getFlag()));
+            }
+            Check.throwsA(IllegalArgumentException.class, () -> Compression.fromFlag(9));
+        });
+
+        Check.test("Compression bounds output and rejects corrupt input", () -> {
+            byte[] bomb = Compression.compress(new byte[100000], Compression.LZ77);
+            Check.throwsA(DecompressException.class, () -> Compression.decompress(bomb, Compression.LZ77, 1000));
+            byte[] rleBomb = Compression.compress(new byte[100000], Compression.RLE);
+            Check.throwsA(DecompressException.class, () -> Compression.decompress(rleBomb, Compression.RLE, 1000));
+            Check.throwsA(DecompressException.class, () -> Compression.decompress(new byte[10], Compression.NONE, 5));
+            Check.throwsA(DecompressException.class, () -> Compression.decompress(new byte[]{3}, Compression.RLE, 100));
+            Check.throwsA(DecompressException.class, () -> Compression.decompress(new byte[]{1, 0x10, 0x00}, Compression.LZ77, 100));
+            Check.throwsA(IllegalArgumentException.class, () -> Compression.decompress(new byte[0], Compression.NONE, -1));
+        });
+
+        Check.test("Compression LZ77 finds matches across the whole window", () -> {
+            byte[] data = noise(4096 + 18);
+            System.arraycopy(data, 0, data, 4096, 18);
+            byte[] packed = Compression.compress(data, Compression.LZ77);
+            Check.isTrue(Arrays.equals(data, Compression.decompress(packed, Compression.LZ77, data.length)), "round trip");
+            Check.isTrue(packed.length < data.length + data.length / 8 - 10, "far match used");
+            byte[] big = new byte[300000];
+            for (int i = 0; i < big.length; i++){
+                big[i] = (byte) (i % 251 ^ i / 4099);
+            }
+            Check.isTrue(Arrays.equals(big, Compression.decompress(Compression.compress(big, Compression.LZ77), Compression.LZ77, big.length)), "large input");
+        });
+
+        Check.test("Compression header flag interop", () -> {
+            CompressionPolicy policy = new CompressionPolicy(Compression.LZ77, 64);
+            byte[] data = telemetry();
+            byte[] framed = policy.frame("log", data);
+            Check.equal(Compression.LZ77.getFlag(), (int) framed[0]);
+            Check.isTrue(Arrays.equals(data, CompressionPolicy.unframe(framed, data.length)), "compressed frame");
+            byte[] raw = policy.frame("log", new byte[]{1, 2, 3});
+            Check.isTrue(Arrays.equals(new byte[]{0, 1, 2, 3}, raw), "uncompressed frame");
+            byte[] rle = new CompressionPolicy(Compression.RLE, 0).frame("log", new byte[100]);
+            Check.equal(Compression.RLE.getFlag(), (int) rle[0]);
+            Check.equal(100, CompressionPolicy.unframe(rle, 100).length);
+            Check.throwsA(DecompressException.class, () -> CompressionPolicy.unframe(new byte[0], 10));
+            Check.throwsA(DecompressException.class, () -> CompressionPolicy.unframe(new byte[]{3, 0}, 10));
+            Check.throwsA(DecompressException.class, () -> CompressionPolicy.unframe(new byte[]{(byte) 0x80, 0}, 10));
+        });
+
+        Check.test("CompressingTransport applies the policy on the TX path", () -> {
+            Loopback a = new Loopback("a");
+            Loopback b = new Loopback("b");
+            Loopback.connect(a, b);
+            CompressingTransport<String> txA = new CompressingTransport<String>(a, new CompressionPolicy(Compression.LZ77, 64), "telemetry");
+            CompressingTransport<String> rxB = new CompressingTransport<String>(b, new CompressionPolicy(Compression.LZ77, 64), "telemetry");
+            byte[] data = Arrays.copyOf(telemetry(), txA.maxPayload());
+            txA.send("b", data);
+            txA.send("b", new byte[]{9});
+            Check.isTrue(a.getSent().peekFirst().length < data.length / 2, "sent compressed");
+            byte[] buf = new byte[rxB.maxPayload()];
+            Check.equal(data.length, rxB.pollRecv(buf).getLength());
+            Check.isTrue(Arrays.equals(data, Arrays.copyOf(buf, data.length)), "payload restored");
+            Check.equal(1, rxB.pollRecv(buf).getLength());
+            Check.equal(9, (int) buf[0]);
+            CompressionPolicy.Stats stats = txA.getPolicy().getStats("telemetry");
+            Check.equal(1L, stats.getCompressed());
+            Check.equal(1L, stats.getSkipped());
+
+            b.inject(new byte[]{7, 1, 2});
+            txA.send("b", data);
+            Check.equal(null, rxB.pollRecv(new byte[16]));
+            Check.equal(2L, rxB.getRejected());
+        });
+    }
+
+    public static void main(String[] args){
+        run();
+        Check.exit();
+    }
+}
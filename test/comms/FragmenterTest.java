@@ -0,0 +1,146 @@
+package comms;
+
+import Util.Check;
+
+import java.util.Arrays;
+import java.util.Collections;
+import java.util.List;
+
+public class FragmenterTest {
+    private static byte[] payload(int size){
+        byte[] data = new byte[size];
+        for (int i = 0; i < size; i++){
+            data[i] = (byte) (i * 31);
+        }
+        return data;
+    }
+
+    public static void run(){
+        Check.test("Fragmenter round trip out of order", () -> {
+            Fragmenter fragmenter = new Fragmenter(32);
+            byte[] data = payload(100);
+            List<byte[]> fragments = fragmenter.split(7, data);
+            Check.equal(5, fragments.size());
+            Collections.reverse(fragments);
+            Reassembler reassembler = new Reassembler(4, 1000);
+            byte[] out = null;
+            for (byte[] fragment : fragments){
+                Check.equal(null, out);
+                out = reassembler.accept(fragment, 0);
+            }
+            Check.isTrue(Arrays.equals(data, out), "payload reassembled");
+            Check.equal(0, reassembler.pending());
+        });
+
+        Check.test("Fragmenter empty payload is one fragment", () -> {
+            Fragmenter fragmenter = new Fragmenter(32);
+            List<byte[]> fragments = fragmenter.split(1, new byte[0]);
+            Check.equal(1, fragments.size());
+            Check.equal(0, new Reassembler(1, 1000).accept(fragments.get(0), 0).length);
+        });
+
+        Check.test("Fragmenter rebuilds single fragments and checks the index", () -> {
+            Fragmenter fragmenter = new Fragmenter(32);
+            byte[] data = payload(100);
+            List<byte[]> fragments = fragmenter.split(3, data);
+            Check.isTrue(Arrays.equals(fragments.get(2), fragmenter.fragment(3, data, 2)), "same bytes");
+            Check.throwsA(IndexOutOfBoundsException.class, () -> fragmenter.fragment(3, data, 5));
+            Check.throwsA(IndexOutOfBoundsException.class, () -> fragmenter.fragment(3, data, -1));
+        });
+
+        Check.test("Reassembler duplicates, missing and retransmit", () -> {
+            Fragmenter fragmenter = new Fragmenter(32);
+            byte[] data = payload(60);
+            List<byte[]> fragments = fragmenter.split(9, data);
+            Reassembler reassembler = new Reassembler(4, 1000);
+            reassembler.accept(fragments.get(0), 0);
+            reassembler.accept(fragments.get(0), 0);
+            Check.equal(1L, reassembler.getDuplicates());
+            Check.equal(Arrays.asList(1, 2), reassembler.missing(9));
+            reassembler.accept(fragmenter.fragment(9, data, 2), 0);
+            byte[] out = reassembler.accept(fragmenter.fragment(9, data, 1), 0);
+            Check.isTrue(Arrays.equals(data, out), "payload reassembled");
+        });
+
+        Check.test("Reassembler detects a corrupt payload", () -> {
+            List<byte[]> fragments = new Fragmenter(32).split(2, payload(40));
+            fragments.get(1)[Fragmenter.HEADER_LEN] ^= 1;
+            Reassembler reassembler = new Reassembler(4, 1000);
+            reassembler.accept(fragments.get(0), 0);
+            Check.equal(null, reassembler.accept(fragments.get(1), 0));
+            Check.equal(1L, reassembler.getCrcFailures());
+        });
+
+        Check.test("Reassembler evicts the oldest and expires stale messages", () -> {
+            Fragmenter fragmenter = new Fragmenter(32);
+            Reassembler reassembler = new Reassembler(2, 100);
+            for (int id = 0; id < 3; id++){
+                reassembler.accept(fragmenter.split(id, payload(60)).get(0), id);
+            }
+            Check.equal(1L, reassembler.getEvicted());
+            Check.equal(Collections.emptyList(), reassembler.missing(0));
+            Check.equal(2, reassembler.expire(200));
+            Check.equal(0, reassembler.pending());
+            Check.equal(2L, reassembler.getExpired());
+        });
+
+        Check.test("Reassembler counts late duplicates of delivered messages", () -> {
+            Fragmenter fragmenter = new Fragmenter(32);
+            Reassembler reassembler = new Reassembler(1, 100);
+            byte[] single = fragmenter.split(1, payload(10)).get(0);
+            Check.isTrue(reassembler.accept(single, 0) != null, "delivered once");
+            Check.equal(null, reassembler.accept(single, 10));
+            Check.equal(1L, reassembler.getDuplicates());
+
+            List<byte[]> fragments = fragmenter.split(2, payload(60));
+            for (byte[] fragment : fragments){
+                reassembler.accept(fragment, 20);
+            }
+            Check.equal(null, reassembler.accept(fragments.get(1), 30));
+            Check.equal(2L, reassembler.getDuplicates());
+            Check.equal(0, reassembler.pending());
+
+            byte[] live = fragmenter.split(3, payload(60)).get(0);
+            reassembler.accept(live, 40);
+            Check.equal(null, reassembler.accept(fragments.get(0), 50));
+            Check.equal(0L, reassembler.getEvicted());
+            Check.equal(Arrays.asList(1, 2), reassembler.missing(3));
+        });
+
+        Check.test("Reassembler forgets delivered messages after the timeout", () -> {
+            Fragmenter fragmenter = new Fragmenter(32);
+            Reassembler reassembler = new Reassembler(4, 100);
+            byte[] single = fragmenter.split(1, payload(10)).get(0);
+            reassembler.accept(single, 0);
+            Check.isTrue(reassembler.accept(single, 200) != null, "id may be reused after the timeout");
+            byte[] reused = fragmenter.split(1, payload(11)).get(0);
+            Check.isTrue(reassembler.accept(reused, 210) != null, "a different payload under a delivered id is new");
+            Check.equal(0L, reassembler.getDuplicates());
+        });
+
+        Check.test("Reassembler drops fragments that disagree with the partial", () -> {
+            Fragmenter fragmenter = new Fragmenter(32);
+            Reassembler reassembler = new Reassembler(4, 1000);
+            byte[] data = payload(60);
+            List<byte[]> fragments = fragmenter.split(5, data);
+            reassembler.accept(fragments.get(0), 0);
+            byte[] other = data.clone();
+            other[0] ^= 1;
+            Check.equal(null, reassembler.accept(fragmenter.split(5, other).get(1), 0));
+            Check.equal(null, reassembler.accept(fragmenter.split(5, payload(100)).get(1), 0));
+            Check.equal(2L, reassembler.getMismatched());
+            reassembler.accept(fragments.get(1), 0);
+            Check.isTrue(Arrays.equals(data, reassembler.accept(fragments.get(2), 0)), "original message intact");
+        });
+
+        Check.test("Reassembler rejects a non-positive limit", () -> {
+            Check.throwsA(IllegalArgumentException.class, () -> new Reassembler(0, 1000));
+            Check.throwsA(IllegalArgumentException.class, () -> new Reassembler(-1, 1000));
+        });
+    }
+
+    public static void main(String[] args){
+        run();
+        Check.exit();
+    }
+}
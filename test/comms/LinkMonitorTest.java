@@ -0,0 +1,95 @@
+package comms;
+
+import Util.Check;
+import Util.Enums.LinkGrade;
+
+public class LinkMonitorTest {
+    public static void run(){
+        Check.test("LinkMonitor smooths RTT and ignores retransmitted samples", () -> {
+            LinkMonitor monitor = new LinkMonitor();
+            monitor.onSend(1, 100, 0);
+            monitor.onAck(1, 10, 100);
+            LinkReport first = monitor.report(100);
+            Check.near(100, first.getRttSmoothedMs(), 1e-9);
+            Check.near(50, first.getRttVarMs(), 1e-9);
+            monitor.onSend(2, 100, 200);
+            monitor.onRetransmit(2, 400);
+            monitor.onAck(2, 10, 1000);
+            Check.near(100, monitor.report(1000).getRttSmoothedMs(), 1e-9);
+            monitor.onSend(3, 100, 1000);
+            monitor.onAck(3, 10, 1200);
+            Check.near(0.875 * 100 + 0.125 * 200, monitor.report(1200).getRttSmoothedMs(), 1e-9);
+        });
+
+        Check.test("LinkMonitor counts unacked packets as lost after the RTO", () -> {
+            LinkMonitor monitor = new LinkMonitor(10, 1000, 1000, 5000);
+            for (int seq = 0; seq < 10; seq++){
+                monitor.onSend(seq, 100, 0);
+            }
+            Check.equal(1000L, monitor.rtoMs());
+            Check.near(0, monitor.report(1000).getLossRateWindow(), 1e-9);
+            Check.equal(10, monitor.getInFlight());
+            LinkReport report = monitor.report(1001);
+            Check.near(1, report.getLossRateWindow(), 1e-9);
+            Check.equal(0, monitor.getInFlight());
+            Check.equal(LinkGrade.LOST, report.getGrade());
+        });
+
+        Check.test("LinkMonitor probes that never return count as lost", () -> {
+            LinkMonitor monitor = new LinkMonitor(4, 1000, 1000, 5000);
+            for (int i = 0; i < 100; i++){
+                monitor.sendProbe(i * 2000L);
+                monitor.expire(i * 2000L + 1500);
+            }
+            Check.equal(0, monitor.getInFlight());
+            Check.near(1, monitor.report(200000).getLossRateWindow(), 1e-9);
+        });
+
+        Check.test("LinkMonitor ignores duplicate and late acks", () -> {
+            LinkMonitor monitor = new LinkMonitor(10, 1000, 1000, 5000);
+            monitor.onSend(1, 100, 0);
+            monitor.onSend(2, 100, 0);
+            monitor.onAck(1, 10, 50);
+            monitor.onAck(1, 10, 60);
+            monitor.onAck(1, 10, 70);
+            monitor.expire(2000);
+            Check.near(0.5, monitor.report(2000).getLossRateWindow(), 1e-9);
+            monitor.onAck(2, 10, 2100);
+            Check.near(0.5, monitor.report(2100).getLossRateWindow(), 1e-9);
+            Check.equal(2100L, monitor.getLastHeardMs());
+        });
+
+        Check.test("LinkMonitor grade recovers with hysteresis", () -> {
+            LinkMonitor monitor = new LinkMonitor(10, 1000, 1000, 5000);
+            long seq = 0;
+            for (int i = 0; i < 10; i++){
+                monitor.onSend(seq++, 100, 0);
+            }
+            monitor.onReceive(10, 1100);
+            Check.equal(LinkGrade.POOR, monitor.report(1100).getGrade());
+            for (int i = 0; i < 8; i++){
+                monitor.onSend(seq, 100, 1100);
+                monitor.onAck(seq++, 10, 1110);
+            }
+            // 2 of the last 10 lost: DEGRADED territory, but POOR holds until 0.2 clears.
+            Check.equal(LinkGrade.POOR, monitor.report(1110).getGrade());
+            monitor.onSend(seq, 100, 1110);
+            monitor.onAck(seq, 10, 1120);
+            Check.equal(LinkGrade.DEGRADED, monitor.report(1120).getGrade());
+        });
+
+        Check.test("LinkMonitor trims old traffic as it is heard", () -> {
+            LinkMonitor monitor = new LinkMonitor(10, 1000, 1000, 5000);
+            for (long t = 0; t < 100000; t += 10){
+                monitor.onReceive(100, t);
+            }
+            Check.isTrue(monitor.getTrafficSamples() <= 101, "traffic bounded by the window without report()");
+            Check.near(100 * 101 * 8, monitor.report(99990).getThroughputBps(), 1e-6);
+        });
+    }
+
+    public static void main(String[] args){
+        run();
+        Check.exit();
+    }
+}
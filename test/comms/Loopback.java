@@ -0,0 +1,55 @@
+package comms;
+
+import java.util.ArrayDeque;
+import java.util.Deque;
+
+// In-memory link for tests: whatever one end sends the other end receives.
+class Loopback implements Transport<String> {
+    private final String name;
+    private final Deque<byte[]> inbox = new ArrayDeque<byte[]>();
+    private final Deque<byte[]> sent = new ArrayDeque<byte[]>();
+    private Loopback peer;
+    private long oversized;
+
+    Loopback(String name){
+        this.name = name;
+    }
+
+    static void connect(Loopback a, Loopback b){
+        a.peer = b;
+        b.peer = a;
+    }
+
+    public int maxPayload(){ return 256;}
+
+    public void send(String to, byte[] data){
+        sent.addLast(data.clone());
+        if (peer != null){
+            peer.inbox.addLast(data.clone());
+        }
+    }
+
+    public Received<String> pollRecv(byte[] buf){
+        byte[] data;
+        while ((data = inbox.pollFirst()) != null){
+            if (data.length > buf.length){
+                oversized++;
+                continue;
+            }
+            System.arraycopy(data, 0, buf, 0, data.length);
+            return new Received<String>(peer == null ? "?" : peer.name, data.length);
+        }
+        return null;
+    }
+
+    public long getOversized(){ return oversized;}
+
+    public String localAddr(){ return name;}
+
+    // Queues bytes as if the peer had sent them.
+    void inject(byte[] data){
+        inbox.addLast(data.clone());
+    }
+
+    Deque<byte[]> getSent(){ return sent;}
+}
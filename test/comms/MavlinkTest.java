@@ -0,0 +1,165 @@
+package comms;
+
+import Util.Check;
+import Util.Enums.DroneCommand;
+
+import java.util.ArrayList;
+import java.util.Arrays;
+import java.util.List;
+
+public class MavlinkTest {
+    // Expected bytes use checksums from the reference crc_accumulate routine of
+    // the MAVLink C library, computed independently of Util.Crc.
+    private static final byte[] HEARTBEAT_ARMED = bytes(
+            0xFD, 0x09, 0x00, 0x00, 0x00, 0x01, 0x01, 0x00, 0x00, 0x00,
+            0x00, 0x00, 0x00, 0x00, 0x02, 0x00, 0x80, 0x04, 0x03, 0xE8, 0xE7);
+    private static final byte[] ACK_ARM = bytes(
+            0xFD, 0x02, 0x00, 0x00, 0x05, 0x01, 0x01, 0x4D, 0x00, 0x00, 0x90, 0x01, 0xB8, 0x2A);
+
+    private static byte[] bytes(int... values){
+        byte[] out = new byte[values.length];
+        for (int i = 0; i < values.length; i++){
+            out[i] = (byte) values[i];
+        }
+        return out;
+    }
+
+    private static Mavlink.CommandLong command(int cmd, float param1, int target){
+        Mavlink.CommandLong command = new Mavlink.CommandLong();
+        command.command = cmd;
+        command.params[0] = param1;
+        command.targetSystem = target;
+        return command;
+    }
+
+    private static byte[] concat(byte[]... parts){
+        byte[] out = new byte[0];
+        for (byte[] part : parts){
+            int at = out.length;
+            out = Arrays.copyOf(out, at + part.length);
+            System.arraycopy(part, 0, out, at, part.length);
+        }
+        return out;
+    }
+
+    public static void run(){
+        Check.test("Mavlink encodes byte-exact fixtures", () -> {
+            Mavlink.Heartbeat heartbeat = new Mavlink.Heartbeat();
+            heartbeat.type = Mavlink.MAV_TYPE_QUADROTOR;
+            heartbeat.baseMode = Mavlink.MAV_MODE_FLAG_SAFETY_ARMED;
+            heartbeat.systemStatus = Mavlink.MAV_STATE_ACTIVE;
+            Check.isTrue(Arrays.equals(HEARTBEAT_ARMED, Mavlink.encode(0, 1, 1, heartbeat)), "heartbeat");
+            Mavlink.CommandAck ack = new Mavlink.CommandAck();
+            ack.command = Mavlink.MAV_CMD_COMPONENT_ARM_DISARM;
+            Check.isTrue(Arrays.equals(ACK_ARM, Mavlink.encode(5, 1, 1, ack)), "ack with trailing zero stripped");
+        });
+
+        Check.test("Mavlink parser skips unknown ids and bad checksums", () -> {
+            byte[] unknown = bytes(0xFD, 0x03, 0x00, 0x00, 0x07, 0x01, 0x01, 0xFD, 0xFD, 0x00, 0xFD, 0xFD, 0xFD, 0x12, 0x34);
+            byte[] corrupt = HEARTBEAT_ARMED.clone();
+            corrupt[15] ^= 1;
+            byte[] stream = concat(new byte[]{0x55}, HEARTBEAT_ARMED, unknown, corrupt, ACK_ARM);
+            Mavlink.Parser parser = new Mavlink.Parser();
+            List<Mavlink.Frame> frames = new ArrayList<Mavlink.Frame>();
+            for (int i = 0; i < stream.length; i += 5){
+                frames.addAll(parser.feed(Arrays.copyOfRange(stream, i, Math.min(stream.length, i + 5))));
+            }
+            Check.equal(2, frames.size());
+            Check.equal(Mavlink.Heartbeat.MSG_ID, frames.get(0).msgId);
+            Check.equal(Mavlink.CommandAck.MSG_ID, frames.get(1).msgId);
+            Check.equal(Mavlink.MAV_CMD_COMPONENT_ARM_DISARM, Mavlink.CommandAck.decode(frames.get(1).payload).command);
+            Check.equal(1L, parser.getUnknownSkipped());
+            Check.equal(1L, parser.getCrcErrors());
+        });
+
+        Check.test("MavlinkEndpoint translates commands", () -> {
+            Check.equal(DroneCommand.ARM, MavlinkEndpoint.translate(command(Mavlink.MAV_CMD_COMPONENT_ARM_DISARM, 1, 1)));
+            Check.equal(DroneCommand.DISARM, MavlinkEndpoint.translate(command(Mavlink.MAV_CMD_COMPONENT_ARM_DISARM, 0, 1)));
+            Check.equal(DroneCommand.RETURN_TO_LAUNCH, MavlinkEndpoint.translate(command(Mavlink.MAV_CMD_NAV_RETURN_TO_LAUNCH, 0, 1)));
+            Check.equal(null, MavlinkEndpoint.translate(command(31000, 0, 1)));
+        });
+
+        Check.test("MavlinkEndpoint heartbeats at 1 Hz", () -> {
+            Loopback link = new Loopback("uav");
+            MavlinkEndpoint<String> endpoint = new MavlinkEndpoint<String>(link, "gcs", 1, 1, cmd -> {});
+            endpoint.setArmed(true);
+            endpoint.poll(0);
+            endpoint.poll(999);
+            Check.equal(1, link.getSent().size());
+            Check.isTrue(Arrays.equals(HEARTBEAT_ARMED, link.getSent().peekFirst()), "heartbeat bytes");
+            endpoint.poll(1000);
+            Check.equal(2, link.getSent().size());
+        });
+
+        Check.test("MavlinkEndpoint acks commands addressed to it", () -> {
+            Loopback link = new Loopback("uav");
+            List<DroneCommand> received = new ArrayList<DroneCommand>();
+            MavlinkEndpoint<String> endpoint = new MavlinkEndpoint<String>(link, "gcs", 1, 1, received::add);
+            link.inject(concat(
+                    Mavlink.encode(0, 255, 190, command(Mavlink.MAV_CMD_COMPONENT_ARM_DISARM, 1, 1)),
+                    Mavlink.encode(1, 255, 190, command(Mavlink.MAV_CMD_NAV_RETURN_TO_LAUNCH, 0, 2)),
+                    Mavlink.encode(2, 255, 190, command(Mavlink.MAV_CMD_NAV_RETURN_TO_LAUNCH, 0, 0)),
+                    Mavlink.encode(3, 255, 190, command(31000, 0, 1))));
+            endpoint.poll(0);
+            Check.equal(Arrays.asList(DroneCommand.ARM, DroneCommand.RETURN_TO_LAUNCH), received);
+            Check.equal(1L, endpoint.getUnsupported());
+            Mavlink.Parser parser = new Mavlink.Parser();
+            List<Integer> results = new ArrayList<Integer>();
+            for (byte[] sent : link.getSent()){
+                for (Mavlink.Frame frame : parser.feed(sent)){
+                    if (frame.msgId == Mavlink.CommandAck.MSG_ID){
+                        results.add(Mavlink.CommandAck.decode(frame.payload).result);
+                    }
+                }
+            }
+            Check.equal(Arrays.asList(Mavlink.MAV_RESULT_ACCEPTED, Mavlink.MAV_RESULT_ACCEPTED, Mavlink.MAV_RESULT_UNSUPPORTED), results);
+        });
+
+        Check.test("MavlinkEndpoint keeps receiving after an oversized datagram", () -> {
+            Loopback link = new Loopback("uav");
+            List<DroneCommand> received = new ArrayList<DroneCommand>();
+            MavlinkEndpoint<String> endpoint = new MavlinkEndpoint<String>(link, "gcs", 1, 1, received::add);
+            link.inject(new byte[2000]);
+            link.inject(Mavlink.encode(0, 255, 190, command(Mavlink.MAV_CMD_COMPONENT_ARM_DISARM, 1, 1)));
+            endpoint.poll(0);
+            Check.equal(Arrays.asList(DroneCommand.ARM), received);
+            Check.equal(1L, endpoint.getOversized());
+        });
+
+        Check.test("MavlinkEndpoint counts receive failures and keeps draining", () -> {
+            Loopback link = new Loopback("uav");
+            int[] failures = {2};
+            Transport<String> flaky = new Transport<String>() {
+                public int maxPayload(){ return link.maxPayload();}
+
+                public void send(String to, byte[] data){ link.send(to, data);}
+
+                public Received<String> pollRecv(byte[] buf) throws CommsException {
+                    if (failures[0]-- > 0){
+                        throw new CommsException("link glitch");
+                    }
+                    return link.pollRecv(buf);
+                }
+
+                public long getOversized(){ return link.getOversized();}
+
+                public String localAddr(){ return link.localAddr();}
+            };
+            List<DroneCommand> received = new ArrayList<DroneCommand>();
+            MavlinkEndpoint<String> endpoint = new MavlinkEndpoint<String>(flaky, "gcs", 1, 1, received::add);
+            link.inject(Mavlink.encode(0, 255, 190, command(Mavlink.MAV_CMD_NAV_RETURN_TO_LAUNCH, 0, 1)));
+            endpoint.poll(0);
+            Check.equal(Arrays.asList(DroneCommand.RETURN_TO_LAUNCH), received);
+            Check.equal(2L, endpoint.getRecvErrors());
+
+            failures[0] = Integer.MAX_VALUE;
+            endpoint.poll(10);
+            Check.equal(2L + MavlinkEndpoint.MAX_RECV_ERRORS_PER_POLL, endpoint.getRecvErrors());
+        });
+    }
+
+    public static void main(String[] args){
+        run();
+        Check.exit();
+    }
+}
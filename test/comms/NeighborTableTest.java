@@ -0,0 +1,46 @@
+package comms;
+
+import Util.Check;
+
+import java.util.Arrays;
+
+public class NeighborTableTest {
+    public static void run(){
+        Check.test("NeighborTable inserts and updates neighbors", () -> {
+            NeighborTable table = new NeighborTable();
+            table.observe(1, -70, 0);
+            table.observe(2, -60, 10);
+            table.observe(1, -50, 20);
+            Check.equal(2, table.size());
+            Check.equal(-50, table.get(1).getRssi());
+            Check.equal(20L, table.get(1).getLastHeardMs());
+            Check.equal(null, table.get(3));
+        });
+
+        Check.test("NeighborTable picks the strongest, then most recent", () -> {
+            NeighborTable table = new NeighborTable();
+            Check.equal(null, table.bestNeighbor());
+            table.observe(1, -70, 0);
+            table.observe(2, -60, 10);
+            table.observe(3, -80, 20);
+            Check.equal(2L, table.bestNeighbor().getAddress());
+            table.observe(4, -60, 30);
+            Check.equal(4L, table.bestNeighbor().getAddress());
+        });
+
+        Check.test("NeighborTable prunes stale entries", () -> {
+            NeighborTable table = new NeighborTable();
+            table.observe(1, -40, 0);
+            table.observe(2, -90, 900);
+            Check.equal(Arrays.asList(), table.prune(1000, 1000));
+            Check.equal(Arrays.asList(1L), table.prune(1001, 1000));
+            Check.equal(2L, table.bestNeighbor().getAddress());
+            Check.equal(1, table.size());
+        });
+    }
+
+    public static void main(String[] args){
+        run();
+        Check.exit();
+    }
+}
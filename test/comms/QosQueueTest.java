@@ -0,0 +1,114 @@
+package comms;
+
+import Util.Check;
+
+import java.util.ArrayList;
+import java.util.Arrays;
+import java.util.List;
+
+public class QosQueueTest {
+    private static List<QosClass> drain(QosQueue queue, int frames, long nowMs){
+        List<QosClass> order = new ArrayList<QosClass>();
+        for (int i = 0; i < frames; i++){
+            QosQueue.Frame frame = queue.poll(nowMs);
+            if (frame == null){
+                break;
+            }
+            order.add(frame.getQos());
+        }
+        return order;
+    }
+
+    public static void run(){
+        Check.test("QosQueue drains by strict priority", () -> {
+            QosQueue queue = new QosQueue(8, 10000);
+            queue.enqueue(QosClass.BULK, new byte[10], 0);
+            queue.enqueue(QosClass.TELEMETRY, new byte[10], 0);
+            queue.enqueue(QosClass.CONTROL, new byte[10], 0);
+            queue.enqueue(QosClass.COMMAND, new byte[10], 0);
+            queue.enqueue(QosClass.CONTROL, new byte[10], 0);
+            Check.equal(Arrays.asList(QosClass.CONTROL, QosClass.CONTROL, QosClass.COMMAND, QosClass.TELEMETRY, QosClass.BULK),
+                    drain(queue, 10, 0));
+            Check.isTrue(queue.isEmpty(), "drained");
+        });
+
+        Check.test("QosQueue bandwidth floor keeps bulk moving", () -> {
+            QosQueue queue = new QosQueue(100, 100000);
+            queue.setBandwidthFloor(QosClass.BULK, 0.25);
+            for (int i = 0; i < 40; i++){
+                queue.enqueue(QosClass.TELEMETRY, new byte[100], 0);
+                queue.enqueue(QosClass.BULK, new byte[100], 0);
+            }
+            List<QosClass> order = drain(queue, 40, 0);
+            int bulk = 0;
+            for (QosClass qos : order){
+                if (qos == QosClass.BULK){
+                    bulk++;
+                }
+            }
+            Check.isTrue(bulk >= 9 && bulk <= 11, "bulk got " + bulk + " of 40 frames");
+        });
+
+        Check.test("QosQueue routes only fragmented classes through the fragmenter", () -> {
+            QosQueue queue = new QosQueue(8, 10000, new Fragmenter(32));
+            queue.enqueue(QosClass.TELEMETRY, new byte[100], 0);
+            queue.enqueue(QosClass.BULK, new byte[100], 0);
+            QosQueue.Frame telemetry = queue.poll(0);
+            Check.equal(100, telemetry.getData().length);
+            Check.isTrue(telemetry.isLast(), "telemetry is one frame");
+            Check.isFalse(QosClass.TELEMETRY.isReliable(), "telemetry best effort");
+            Check.isTrue(QosClass.CONTROL.isReliable() && QosClass.COMMAND.isReliable(), "control and command reliable");
+            Check.isTrue(QosClass.BULK.isReliable() && QosClass.BULK.isFragmented(), "bulk reliable and fragmented");
+            List<QosClass> rest = drain(queue, 10, 0);
+            Check.equal(5, rest.size());
+            Check.equal(5L, queue.getStats(QosClass.BULK).getFramesSent());
+        });
+
+        Check.test("QosQueue stats under a scripted backlog", () -> {
+            QosQueue queue = new QosQueue(2, 10000);
+            queue.enqueue(QosClass.TELEMETRY, new byte[10], 0);
+            queue.enqueue(QosClass.TELEMETRY, new byte[10], 10);
+            Check.isFalse(queue.enqueue(QosClass.TELEMETRY, new byte[10], 20), "over capacity");
+            queue.poll(50);
+            queue.poll(200);
+            QosQueue.Stats stats = queue.getStats(QosClass.TELEMETRY);
+            Check.equal(2L, stats.getQueued());
+            Check.equal(2L, stats.getSent());
+            Check.equal(1L, stats.getDropped());
+            Check.equal(190L, stats.getMaxQueueDelayMs());
+        });
+
+        Check.test("QosQueue interleaves control between bulk fragments", () -> {
+            QosQueue queue = new QosQueue(8, 100000, new Fragmenter(32));
+            queue.enqueue(QosClass.BULK, new byte[200], 0);
+            QosQueue.Frame first = queue.poll(0);
+            Check.equal(QosClass.BULK, first.getQos());
+            int total = first.getItem().getFramesRemaining() + 1;
+            queue.enqueue(QosClass.CONTROL, new byte[4], 5);
+            QosQueue.Frame next = queue.poll(10);
+            Check.equal(QosClass.CONTROL, next.getQos());
+            Check.equal(5L, queue.getStats(QosClass.CONTROL).getLastItemLatencyMs());
+            List<QosClass> rest = drain(queue, 100, 40);
+            Check.equal(total - 1, rest.size());
+            Check.equal(1L, queue.getStats(QosClass.BULK).getSent());
+            Check.equal(40L, queue.getStats(QosClass.BULK).getLastItemLatencyMs());
+            Check.equal((long) total, queue.getStats(QosClass.BULK).getFramesSent());
+        });
+
+        Check.test("QosQueue starvation guard lets bulk through", () -> {
+            QosQueue queue = new QosQueue(100, 100000);
+            queue.setStarvationLimit(3);
+            queue.enqueue(QosClass.BULK, new byte[10], 0);
+            for (int i = 0; i < 10; i++){
+                queue.enqueue(QosClass.CONTROL, new byte[10], 0);
+            }
+            List<QosClass> order = drain(queue, 4, 0);
+            Check.equal(Arrays.asList(QosClass.CONTROL, QosClass.CONTROL, QosClass.CONTROL, QosClass.BULK), order);
+        });
+    }
+
+    public static void main(String[] args){
+        run();
+        Check.exit();
+    }
+}
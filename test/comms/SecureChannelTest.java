@@ -0,0 +1,131 @@
+package comms;
+
+import Util.Check;
+
+import java.nio.ByteBuffer;
+import java.nio.charset.StandardCharsets;
+import java.util.Arrays;
+
+public class SecureChannelTest {
+    private static final byte[] PSK = "onboarding-key-0123456789abcdef".getBytes(StandardCharsets.US_ASCII);
+
+    private static SecureChannel[] pair(){
+        byte[] a = new byte[SecureChannel.NONCE_LEN];
+        byte[] b = new byte[SecureChannel.NONCE_LEN];
+        Arrays.fill(b, (byte) 1);
+        return new SecureChannel[]{new SecureChannel(PSK, a, b, true), new SecureChannel(PSK, a, b, false)};
+    }
+
+    public static void run(){
+        Check.test("SecureChannel round trip both directions", () -> {
+            SecureChannel[] p = pair();
+            byte[] msg = "arm".getBytes(StandardCharsets.US_ASCII);
+            Check.isTrue(Arrays.equals(msg, p[1].open(p[0].seal(msg))), "initiator to responder");
+            Check.isTrue(Arrays.equals(msg, p[0].open(p[1].seal(msg))), "responder to initiator");
+        });
+
+        Check.test("SecureChannel rejects a flipped bit", () -> {
+            SecureChannel[] p = pair();
+            byte[] packet = p[0].seal(new byte[]{1, 2, 3});
+            packet[SecureChannel.HEADER_LEN] ^= 0x10;
+            SecureChannelException e = Check.throwsA(SecureChannelException.class, () -> p[1].open(packet));
+            Check.equal(SecureChannelException.Reason.AUTH_FAILED, e.getReason());
+            Check.equal(1L, p[1].getRejected(SecureChannelException.Reason.AUTH_FAILED));
+        });
+
+        Check.test("SecureChannel replay window", () -> {
+            SecureChannel[] p = pair();
+            byte[][] packets = new byte[70][];
+            for (int i = 0; i < packets.length; i++){
+                packets[i] = p[0].seal(new byte[]{(byte) i});
+            }
+            p[1].open(packets[2]);
+            p[1].open(packets[0]);
+            p[1].open(packets[1]);
+            Check.throwsA(SecureChannelException.class, () -> p[1].open(packets[1]));
+            p[1].open(packets[69]);
+            Check.throwsA(SecureChannelException.class, () -> p[1].open(packets[3]));
+            p[1].open(packets[10]);
+            Check.equal(2L, p[1].getRejected(SecureChannelException.Reason.REPLAYED));
+        });
+
+        Check.test("SecureChannel reports a negative counter as malformed", () -> {
+            SecureChannel[] p = pair();
+            byte[] packet = p[0].seal(new byte[4]);
+            ByteBuffer.wrap(packet).putLong(0, -5);
+            SecureChannelException e = Check.throwsA(SecureChannelException.class, () -> p[1].open(packet));
+            Check.equal(SecureChannelException.Reason.MALFORMED, e.getReason());
+            Check.equal(0L, p[1].getRejected(SecureChannelException.Reason.REPLAYED));
+        });
+
+        Check.test("SecureChannel key derivation is deterministic", () -> {
+            byte[] a = SecureChannel.newHandshakeNonce();
+            byte[] b = SecureChannel.newHandshakeNonce();
+            Check.isTrue(Arrays.equals(SecureChannel.deriveKey(PSK, a, b, "x"), SecureChannel.deriveKey(PSK, a, b, "x")), "same inputs");
+            Check.isFalse(Arrays.equals(SecureChannel.deriveKey(PSK, a, b, "x"), SecureChannel.deriveKey(PSK, b, a, "x")), "nonce order matters");
+            Check.isFalse(Arrays.equals(SecureChannel.deriveKey(PSK, a, b, "x"), SecureChannel.deriveKey(PSK, a, b, "y")), "label matters");
+        });
+
+        Check.test("SecureHandshake agrees on keys", () -> {
+            SecureHandshake initiator = new SecureHandshake(PSK);
+            SecureHandshake responder = new SecureHandshake(PSK);
+            initiator.finish(responder.respond(initiator.start()));
+            Check.isTrue(initiator.isComplete() && responder.isComplete(), "both complete");
+            byte[] msg = {4, 2};
+            Check.isTrue(Arrays.equals(msg, responder.getChannel().open(initiator.getChannel().seal(msg))), "keys agree");
+        });
+
+        Check.test("SecureHandshake rejects a peer without the key", () -> {
+            SecureHandshake initiator = new SecureHandshake(PSK);
+            SecureHandshake impostor = new SecureHandshake("wrong".getBytes(StandardCharsets.US_ASCII));
+            byte[] reply = impostor.respond(initiator.start());
+            SecureChannelException e = Check.throwsA(SecureChannelException.class, () -> initiator.finish(reply));
+            Check.equal(SecureChannelException.Reason.AUTH_FAILED, e.getReason());
+            Check.isFalse(initiator.isComplete(), "not complete");
+            Check.throwsA(SecureChannelException.class, () -> impostor.respond(new byte[3]));
+        });
+
+        Check.test("SecureTransport wraps a transport and drops forgeries", () -> {
+            Loopback a = new Loopback("a");
+            Loopback b = new Loopback("b");
+            Loopback.connect(a, b);
+            SecureChannel[] p = pair();
+            SecureTransport<String> secureA = new SecureTransport<String>(a, p[0]);
+            SecureTransport<String> secureB = new SecureTransport<String>(b, p[1]);
+            Check.equal(256 - SecureChannel.HEADER_LEN - SecureChannel.TAG_LEN, secureA.maxPayload());
+            a.send("b", new byte[40]);
+            secureA.send("b", new byte[]{7, 7, 7});
+            byte[] buf = new byte[secureB.maxPayload()];
+            Transport.Received<String> received = secureB.pollRecv(buf);
+            Check.equal(3, received.getLength());
+            Check.equal("a", received.getFrom());
+            Check.equal(7, (int) buf[0]);
+            Check.equal(null, secureB.pollRecv(buf));
+            Check.equal(1L, p[1].getRejected(SecureChannelException.Reason.AUTH_FAILED));
+            Check.throwsA(CommsException.class, () -> secureA.send("b", new byte[secureA.maxPayload() + 1]));
+        });
+
+        Check.test("SecureTransport drops oversized packets and keeps receiving", () -> {
+            Loopback a = new Loopback("a");
+            Loopback b = new Loopback("b");
+            Loopback.connect(a, b);
+            SecureChannel[] p = pair();
+            SecureTransport<String> secureA = new SecureTransport<String>(a, p[0]);
+            SecureTransport<String> secureB = new SecureTransport<String>(b, p[1]);
+            b.inject(new byte[4000]);
+            secureA.send("b", new byte[100]);
+            secureA.send("b", new byte[]{5});
+            byte[] small = new byte[16];
+            Transport.Received<String> received = secureB.pollRecv(small);
+            Check.equal(1, received.getLength());
+            Check.equal(5, (int) small[0]);
+            Check.equal(2L, secureB.getOversized());
+            Check.equal(0L, p[1].getRejected(SecureChannelException.Reason.AUTH_FAILED));
+        });
+    }
+
+    public static void main(String[] args){
+        run();
+        Check.exit();
+    }
+}
@@ -0,0 +1,66 @@
+package comms;
+
+import Util.Check;
+
+import java.util.ArrayList;
+import java.util.Arrays;
+import java.util.List;
+
+public class SlipTest {
+    public static void run(){
+        Check.test("Slip escapes END and ESC", () -> {
+            byte[] payload = {1, (byte) Slip.END, 2, (byte) Slip.ESC, 3};
+            byte[] encoded = Slip.encode(payload);
+            Check.isTrue(Arrays.equals(new byte[]{(byte) 0xC0, 1, (byte) 0xDB, (byte) 0xDC, 2, (byte) 0xDB, (byte) 0xDD, 3, (byte) 0xC0}, encoded), "encoding");
+            Check.isTrue(Arrays.equals(payload, Slip.decode(encoded)), "round trip");
+        });
+
+        Check.test("Slip decode rejects malformed frames", () -> {
+            Check.throwsA(FrameException.class, () -> Slip.decode(new byte[]{1, (byte) Slip.ESC}));
+            Check.throwsA(FrameException.class, () -> Slip.decode(new byte[]{1, (byte) Slip.ESC, 5}));
+            Check.throwsA(FrameException.class, () -> Slip.decode(new byte[]{1, (byte) Slip.END, 2}));
+        });
+
+        Check.test("Slip decoder handles split input and bad escapes", () -> {
+            byte[] stream = new byte[0];
+            for (byte[] part : Arrays.asList(Slip.encode(new byte[]{1, (byte) 0xC0}), new byte[]{7, (byte) 0xDB, 9, (byte) 0xC0}, Slip.encode(new byte[]{2}))){
+                int at = stream.length;
+                stream = Arrays.copyOf(stream, at + part.length);
+                System.arraycopy(part, 0, stream, at, part.length);
+            }
+            Slip.Decoder decoder = new Slip.Decoder();
+            List<byte[]> frames = new ArrayList<byte[]>();
+            for (byte b : stream){
+                frames.addAll(decoder.feed(new byte[]{b}));
+            }
+            Check.equal(2, frames.size());
+            Check.isTrue(Arrays.equals(new byte[]{1, (byte) 0xC0}, frames.get(0)), "first frame");
+            Check.isTrue(Arrays.equals(new byte[]{2}, frames.get(1)), "second frame");
+            Check.equal(1L, decoder.getErrors());
+        });
+
+        Check.test("Slip decoder drops frames over the MTU", () -> {
+            Slip.Decoder decoder = new Slip.Decoder(4);
+            byte[] noise = new byte[10000];
+            Arrays.fill(noise, (byte) 0x41);
+            Check.equal(0, decoder.feed(noise).size());
+            List<byte[]> frames = decoder.feed(concat(new byte[]{(byte) Slip.END}, Slip.encode(new byte[]{1, 2, 3, 4})));
+            Check.equal(1, frames.size());
+            Check.equal(4, frames.get(0).length);
+            Check.equal(1L, decoder.getDropped());
+            Check.equal(0, decoder.feed(Slip.encode(new byte[]{1, 2, 3, 4, 5})).size());
+            Check.equal(2L, decoder.getDropped());
+        });
+    }
+
+    private static byte[] concat(byte[] a, byte[] b){
+        byte[] out = Arrays.copyOf(a, a.length + b.length);
+        System.arraycopy(b, 0, out, a.length, b.length);
+        return out;
+    }
+
+    public static void main(String[] args){
+        run();
+        Check.exit();
+    }
+}
@@ -0,0 +1,55 @@
+package comms;
+
+import Util.Check;
+import Util.Enums.Role;
+import model.Node;
+import model.Swarm;
+
+import java.util.Arrays;
+import java.util.Map;
+import java.util.Random;
+
+// Drives Swarm from outside package model, the way the comms stack does.
+public class SwarmLinkTest {
+    public static void run(){
+        Check.test("Swarm keys members by builder-assigned node ids", () -> {
+            Swarm swarm = new Swarm(1);
+            for (long id = 1; id <= 3; id++){
+                swarm.join(new Node.Builder().nodeId(id).callSign("uav" + id).build(), 0);
+            }
+            Check.equal(3, swarm.size());
+            swarm.assignRoles();
+            Check.equal(Role.LEADER, swarm.roleOf(1));
+            Check.equal(Role.RELAY, swarm.roleOf(2));
+            Check.equal(Role.SCOUT, swarm.roleOf(3));
+            Check.equal("uav2", swarm.getMember(2).getNode().getCallSign());
+        });
+
+        Check.test("Swarm gossip over a transport", () -> {
+            Loopback linkA = new Loopback("1");
+            Loopback linkB = new Loopback("2");
+            Loopback.connect(linkA, linkB);
+            Swarm a = new Swarm(1);
+            Swarm b = new Swarm(2);
+            a.join(new Node.Builder().nodeId(2).build(), 0);
+            a.join(new Node.Builder().nodeId(3).build(), 0);
+            for (Map.Entry<Long, byte[]> out : a.gossipTick(new Random(1), 2, 0).entrySet()){
+                if (out.getKey() == 2){
+                    linkA.send("2", out.getValue());
+                }
+            }
+            byte[] buf = new byte[linkB.maxPayload()];
+            Transport.Received<String> received = linkB.pollRecv(buf);
+            Check.isTrue(b.mergeGossip(Long.parseLong(received.getFrom()), Arrays.copyOf(buf, received.getLength()), 10), "digest accepted");
+            Check.equal(2, b.size());
+            Check.isTrue(b.getMember(3) != null, "second-hand member learned");
+            Check.equal(1L, b.getMember(1).getVersion());
+            Check.equal(10L, b.getMember(1).getLastHeardMs());
+        });
+    }
+
+    public static void main(String[] args){
+        run();
+        Check.exit();
+    }
+}
@@ -0,0 +1,121 @@
+package comms;
+
+import Util.Check;
+
+import java.io.ByteArrayInputStream;
+import java.io.ByteArrayOutputStream;
+import java.io.DataOutputStream;
+import java.util.ArrayList;
+import java.util.Arrays;
+import java.util.List;
+
+public class TrafficCaptureTest {
+    private static byte[] capture(List<TrafficCapture.Record> records) throws Exception {
+        ByteArrayOutputStream out = new ByteArrayOutputStream();
+        TrafficCapture.write(records, out);
+        return out.toByteArray();
+    }
+
+    private static List<TrafficCapture.Record> recordSession() throws Exception {
+        Loopback local = new Loopback("uav");
+        Loopback remote = new Loopback("gcs");
+        Loopback.connect(local, remote);
+        long[] now = {1000};
+        TrafficRecorder<String> recorder = new TrafficRecorder<String>(local, () -> now[0]);
+        remote.send("uav", new byte[]{1});
+        recorder.pollRecv(new byte[16]);
+        now[0] = 1100;
+        recorder.send("gcs", new byte[]{2, 2});
+        remote.send("uav", new byte[]{3, 3, 3});
+        now[0] = 1250;
+        recorder.pollRecv(new byte[16]);
+        return new ArrayList<TrafficCapture.Record>(recorder.getRecords());
+    }
+
+    public static void run(){
+        Check.test("TrafficCapture round trip through the file format", () -> {
+            List<TrafficCapture.Record> records = recordSession();
+            List<TrafficCapture.Record> back = TrafficCapture.read(new ByteArrayInputStream(capture(records)));
+            Check.equal(3, back.size());
+            for (int i = 0; i < records.size(); i++){
+                Check.equal(records.get(i).getDirection(), back.get(i).getDirection());
+                Check.equal(records.get(i).getTimestampMs(), back.get(i).getTimestampMs());
+                Check.equal(records.get(i).getPeer(), back.get(i).getPeer());
+                Check.isTrue(Arrays.equals(records.get(i).getPayload(), back.get(i).getPayload()), "payload " + i);
+            }
+        });
+
+        Check.test("TrafficRecorder keeps the newest records and counts dropped ones", () -> {
+            Loopback local = new Loopback("uav");
+            Loopback.connect(local, new Loopback("gcs"));
+            TrafficRecorder<String> recorder = new TrafficRecorder<String>(local, () -> 0L, 2);
+            for (int i = 0; i < 5; i++){
+                recorder.send("gcs", new byte[]{(byte) i});
+            }
+            List<TrafficCapture.Record> records = recorder.getRecords();
+            Check.equal(2, records.size());
+            Check.equal(3L, recorder.getDropped());
+            Check.equal((byte) 3, records.get(0).getPayload()[0]);
+            Check.equal((byte) 4, records.get(1).getPayload()[0]);
+        });
+
+        Check.test("TrafficReplayer replays with original timing", () -> {
+            long[] now = {0};
+            TrafficReplayer replayer = new TrafficReplayer(recordSession(), () -> now[0], 64);
+            byte[] buf = new byte[64];
+            Check.equal(1, replayer.pollRecv(buf).getLength());
+            Check.equal(null, replayer.pollRecv(buf));
+            now[0] = 249;
+            Check.equal(null, replayer.pollRecv(buf));
+            now[0] = 250;
+            Transport.Received<String> received = replayer.pollRecv(buf);
+            Check.equal(3, received.getLength());
+            Check.equal("gcs", received.getFrom());
+            Check.isTrue(replayer.isFinished(), "finished");
+        });
+
+        Check.test("TrafficReplayer without a clock is as fast as possible", () -> {
+            TrafficReplayer replayer = new TrafficReplayer(recordSession(), null, 64);
+            byte[] buf = new byte[64];
+            Check.equal(1, replayer.pollRecv(buf).getLength());
+            Check.equal(3, replayer.pollRecv(buf).getLength());
+            Check.equal(null, replayer.pollRecv(buf));
+            replayer.send("gcs", new byte[]{9});
+            Check.equal(1, replayer.getSent().size());
+        });
+
+        Check.test("TrafficCapture rejects bad magic, versions and truncation", () -> {
+            byte[] good = capture(recordSession());
+            byte[] magic = good.clone();
+            magic[0] = 'X';
+            Check.throwsA(CommsException.class, () -> TrafficCapture.read(new ByteArrayInputStream(magic)));
+            byte[] version = good.clone();
+            version[5] = 9;
+            Check.throwsA(CommsException.class, () -> TrafficCapture.read(new ByteArrayInputStream(version)));
+            byte[] truncated = Arrays.copyOf(good, good.length - 1);
+            Check.throwsA(CommsException.class, () -> TrafficCapture.read(new ByteArrayInputStream(truncated)));
+        });
+
+        Check.test("TrafficCapture rejects an oversized record length", () -> {
+            ByteArrayOutputStream bytes = new ByteArrayOutputStream();
+            DataOutputStream out = new DataOutputStream(bytes);
+            out.writeInt(TrafficCapture.MAGIC);
+            out.writeShort(TrafficCapture.VERSION);
+            out.writeByte(TrafficCapture.Direction.RECEIVED.ordinal());
+            out.writeLong(0);
+            out.writeUTF("gcs");
+            out.writeInt(Integer.MAX_VALUE);
+            CommsException e = Check.throwsA(CommsException.class, () -> TrafficCapture.read(new ByteArrayInputStream(bytes.toByteArray())));
+            Check.isTrue(e.getMessage().contains("length"), e.getMessage());
+            List<TrafficCapture.Record> big = Arrays.asList(new TrafficCapture.Record(
+                    TrafficCapture.Direction.RECEIVED, 0, "gcs", new byte[100]));
+            Check.throwsA(CommsException.class, () -> TrafficCapture.read(new ByteArrayInputStream(capture(big)), 64));
+            Check.throwsA(IllegalArgumentException.class, () -> new TrafficReplayer(big, null, 64));
+        });
+    }
+
+    public static void main(String[] args){
+        run();
+        Check.exit();
+    }
+}
@@ -0,0 +1,62 @@
+package comms;
+
+import Util.Check;
+
+import java.io.ByteArrayInputStream;
+import java.io.ByteArrayOutputStream;
+import java.net.InetAddress;
+import java.net.InetSocketAddress;
+import java.util.Arrays;
+
+public class TransportTest {
+    public static void run(){
+        Check.test("SerialTransport drops an oversized frame and delivers the next", () -> {
+            byte[] big = {1, 2, 3, 4, 5, 6};
+            byte[] payload = {7, 8};
+            ByteArrayOutputStream stream = new ByteArrayOutputStream();
+            stream.write(Slip.encode(big));
+            stream.write(Slip.encode(payload));
+            SerialTransport serial = new SerialTransport("ttyS0", new ByteArrayInputStream(stream.toByteArray()), new ByteArrayOutputStream(), 64);
+            byte[] buf = new byte[4];
+            Transport.Received<String> received = serial.pollRecv(buf);
+            Check.equal(2, received.getLength());
+            Check.isTrue(Arrays.equals(payload, Arrays.copyOf(buf, 2)), "payload intact");
+            Check.equal(1L, serial.getOversized());
+            Check.equal(null, serial.pollRecv(buf));
+        });
+
+        Check.test("UdpTransport drops an oversized datagram and delivers the next", () -> {
+            InetSocketAddress loopback = new InetSocketAddress(InetAddress.getLoopbackAddress(), 0);
+            UdpTransport a = new UdpTransport(loopback, 4000);
+            UdpTransport b = new UdpTransport(loopback);
+            try {
+                byte[] payload = {9, 8, 7, 6, 5, 4, 3, 2};
+                a.send(b.localAddr(), new byte[2000]);
+                a.send(b.localAddr(), payload);
+                byte[] buf = new byte[b.maxPayload()];
+                Transport.Received<InetSocketAddress> received = null;
+                long deadline = System.currentTimeMillis() + 2000;
+                while (received == null && System.currentTimeMillis() < deadline){
+                    received = b.pollRecv(buf);
+                    if (received == null){
+                        Thread.sleep(5);
+                    }
+                }
+                Check.isTrue(received != null, "datagram after the oversized one arrived");
+                Check.equal(8, received.getLength());
+                Check.equal(a.localAddr().getPort(), received.getFrom().getPort());
+                Check.isTrue(Arrays.equals(payload, Arrays.copyOf(buf, 8)), "payload intact");
+                Check.equal(1L, b.getOversized());
+                Check.equal(null, b.pollRecv(buf));
+            } finally {
+                a.close();
+                b.close();
+            }
+        });
+    }
+
+    public static void main(String[] args){
+        run();
+        Check.exit();
+    }
+}
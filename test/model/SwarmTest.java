@@ -0,0 +1,119 @@
+package model;
+
+import Util.Check;
+import Util.Enums.Role;
+
+import java.nio.ByteBuffer;
+import java.util.Map;
+import java.util.Random;
+
+public class SwarmTest {
+    public static void run(){
+        Check.test("Swarm roles fail over to the lowest surviving id", () -> {
+            Swarm swarm = new Swarm();
+            for (long id = 1; id <= 4; id++){
+                swarm.join(node(id), 0);
+            }
+            swarm.assignRoles();
+            Check.equal(Role.LEADER, swarm.roleOf(1));
+            Check.equal(Role.RELAY, swarm.roleOf(2));
+            Check.equal(Role.SCOUT, swarm.roleOf(3));
+            swarm.heard(2, 500);
+            swarm.heard(3, 500);
+            swarm.heard(4, 500);
+            Check.equal(1, swarm.pruneStale(1000, 600).size());
+            swarm.assignRoles();
+            Check.equal(Role.RELAY, swarm.roleOf(2));
+            Check.equal(Role.LEADER, swarm.roleOf(3));
+            Check.equal(Role.SCOUT, swarm.roleOf(4));
+        });
+
+        Check.test("Swarm gossip spreads membership", () -> {
+            Swarm a = new Swarm(1);
+            Swarm b = new Swarm(2);
+            Swarm c = new Swarm(3);
+            Swarm d = new Swarm(4);
+            b.mergeGossip(1, digestOf(), 0);
+            b.mergeGossip(4, digestOf(), 0);
+            for (Map.Entry<Long, byte[]> out : b.gossipTick(new Random(1), 2, 0).entrySet()){
+                (out.getKey() == 1 ? a : d).mergeGossip(2, out.getValue(), 0);
+            }
+            Check.isTrue(a.getMember(4) != null, "gossip did not reach a");
+            Check.isTrue(d.getMember(1) != null, "gossip did not reach d");
+            c.mergeGossip(1, a.gossipTick(new Random(1), 0, 0).getOrDefault(3L, digestOf(1, 1, 2, 1)), 0);
+            Check.isTrue(c.getMember(2) != null, "second-hand member missing");
+            Check.equal(-1L, c.getMember(2).getLastHeardMs());
+            Check.equal(0L, c.getMember(1).getLastHeardMs());
+        });
+
+        Check.test("Swarm gossip does not resurrect a pruned member", () -> {
+            Swarm swarm = new Swarm(1);
+            swarm.mergeGossip(2, digestOf(2, 5, 3, 7), 0);
+            swarm.mergeGossip(2, digestOf(2, 6), 1000);
+            Check.equal(3L, swarm.pruneStale(1500, 1000).get(0));
+            swarm.mergeGossip(2, digestOf(2, 7, 3, 7), 1600);
+            Check.equal(null, swarm.getMember(3));
+            swarm.mergeGossip(2, digestOf(2, 8, 3, 8), 1700);
+            Check.equal(8L, swarm.getMember(3).getVersion());
+        });
+
+        Check.test("Swarm tombstones expire after the rejoin guard", () -> {
+            Swarm swarm = new Swarm(1);
+            swarm.setRejoinGuardMs(2000);
+            swarm.mergeGossip(2, digestOf(2, 5, 3, 7), 0);
+            swarm.mergeGossip(2, digestOf(2, 6), 1000);
+            swarm.pruneStale(1500, 1000);
+            Check.equal(1, swarm.getTombstoneCount());
+            swarm.mergeGossip(2, digestOf(2, 7, 3, 7), 3500);
+            Check.equal(null, swarm.getMember(3));
+            swarm.mergeGossip(2, digestOf(2, 8, 3, 7), 3501);
+            Check.equal(0, swarm.getTombstoneCount());
+            Check.equal(7L, swarm.getMember(3).getVersion());
+        });
+
+        Check.test("Swarm second-hand versions do not refresh lastHeardMs", () -> {
+            Swarm swarm = new Swarm(1);
+            swarm.join(node(3), 100);
+            swarm.mergeGossip(2, digestOf(3, 4), 900);
+            Swarm.Member member = swarm.getMember(3);
+            Check.equal(100L, member.getLastHeardMs());
+            Check.equal(900L, member.getLastAdvancedMs());
+            swarm.mergeGossip(2, digestOf(3, 4), 2000);
+            Check.equal(3L, swarm.pruneStale(2000, 1000).get(0));
+        });
+
+        Check.test("Swarm rejects malformed digests", () -> {
+            Swarm swarm = new Swarm(1);
+            Check.isFalse(swarm.mergeGossip(2, new byte[3], 0), "short digest accepted");
+            byte[] lying = ByteBuffer.allocate(4 + 16).putInt(2).putLong(5).putLong(1).array();
+            Check.isFalse(swarm.mergeGossip(2, lying, 0), "lying count accepted");
+            byte[] negative = ByteBuffer.allocate(4).putInt(-1).array();
+            Check.isFalse(swarm.mergeGossip(2, negative, 0), "negative count accepted");
+            Check.equal(0, swarm.size());
+            Check.isTrue(swarm.mergeGossip(2, digestOf(), 0), "empty digest rejected");
+        });
+
+        Check.test("Swarm without a local id cannot gossip", () -> {
+            Check.throwsA(IllegalStateException.class, () -> new Swarm().gossipTick(new Random(), 1, 0));
+        });
+    }
+
+    private static Node node(long id){
+        return new Node.Builder().nodeId(id).build();
+    }
+
+    // Alternating node id, version pairs.
+    private static byte[] digestOf(long... pairs){
+        ByteBuffer buffer = ByteBuffer.allocate(4 + pairs.length * 8);
+        buffer.putInt(pairs.length / 2);
+        for (long value : pairs){
+            buffer.putLong(value);
+        }
+        return buffer.array();
+    }
+
+    public static void main(String[] args){
+        run();
+        Check.exit();
+    }
+}
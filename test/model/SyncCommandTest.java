@@ -0,0 +1,70 @@
+package model;
+
+import Util.Check;
+import Util.Enums.DroneCommand;
+import Util.Enums.RoundState;
+
+import java.util.Arrays;
+import java.util.Collections;
+
+public class SyncCommandTest {
+    public static void run(){
+        Check.test("SyncCommand completes only after all three followers ack", () -> {
+            SyncCommand sync = new SyncCommand(1000);
+            int round = sync.issue(DroneCommand.TAKEOFF, Arrays.asList(2L, 3L, 4L), 0);
+            Check.equal(DroneCommand.TAKEOFF, sync.getCommand());
+            Check.isTrue(sync.onAck(3, round), "ack from 3");
+            Check.isTrue(sync.onAck(2, round), "ack from 2");
+            Check.isFalse(sync.allAcked(), "4 has not acked");
+            Check.equal(RoundState.PENDING, sync.poll(500));
+            Check.isTrue(sync.onAck(4, round), "ack from 4");
+            Check.isTrue(sync.allAcked(), "all acked");
+            Check.equal(RoundState.COMPLETE, sync.poll(5000));
+            Check.equal(Collections.emptyList(), sync.nonResponders());
+        });
+
+        Check.test("SyncCommand times out and names the laggards", () -> {
+            SyncCommand sync = new SyncCommand(1000);
+            int round = sync.issue(DroneCommand.LAND, Arrays.asList(4L, 2L, 3L), 100);
+            sync.onAck(3, round);
+            Check.equal(RoundState.PENDING, sync.poll(1099));
+            Check.equal(RoundState.TIMED_OUT, sync.poll(1100));
+            Check.equal(Arrays.asList(2L, 4L), sync.nonResponders());
+            Check.isFalse(sync.onAck(2, round), "ack after timeout");
+            Check.isFalse(sync.allAcked(), "timed out round is not complete");
+        });
+
+        Check.test("SyncCommand ignores stale, duplicate and unknown acks", () -> {
+            SyncCommand sync = new SyncCommand(1000);
+            int first = sync.issue(DroneCommand.ARM, Arrays.asList(2L, 3L), 0);
+            int second = sync.issue(DroneCommand.TAKEOFF, Arrays.asList(2L, 3L), 10);
+            Check.isFalse(sync.onAck(2, first), "ack for an earlier round");
+            Check.isFalse(sync.onAck(9, second), "ack from an unknown node");
+            Check.isTrue(sync.onAck(2, second), "ack from 2");
+            Check.isTrue(sync.onAck(2, second), "duplicate ack is harmless");
+            Check.isFalse(sync.allAcked(), "duplicate ack must not complete the round");
+            Check.equal(Arrays.asList(3L), sync.nonResponders());
+        });
+
+        Check.test("SyncCommand takes followers from the swarm", () -> {
+            Swarm swarm = new Swarm(1);
+            for (long id = 1; id <= 3; id++){
+                swarm.join(new Node.Builder().nodeId(id).build(), 0);
+            }
+            SyncCommand sync = new SyncCommand(1000);
+            int round = sync.issue(DroneCommand.HOVER, swarm, 0);
+            Check.equal(Arrays.asList(2L, 3L), sync.nonResponders());
+            sync.onAck(2, round);
+            sync.onAck(3, round);
+            Check.isTrue(sync.allAcked(), "local node is not a follower");
+            SyncCommand alone = new SyncCommand(1000);
+            alone.issue(DroneCommand.LAND, Collections.<Long>emptyList(), 0);
+            Check.isTrue(alone.allAcked(), "a round with no followers completes at once");
+        });
+    }
+
+    public static void main(String[] args){
+        run();
+        Check.exit();
+    }
+}
@@ -0,0 +1,69 @@
+package navigation;
+
+import Util.Check;
+import Util.Math3d.Vec3;
+
+import java.nio.ByteBuffer;
+import java.nio.ByteOrder;
+import java.util.List;
+
+public class FlightRecorderTest {
+    public static void run(){
+        Check.test("FlightRecorder keeps records in order", () -> {
+            FlightRecorder recorder = new FlightRecorder(8);
+            recorder.update(10, new Vec3(1, 2, 3), new Vec3(1, 2, 2));
+            recorder.update(20, new Vec3(1, 2, 3), new Vec3(1, 2, 3));
+            List<FlightRecorder.Record> records = recorder.getRecords();
+            Check.equal(2, records.size());
+            Check.equal(10L, records.get(0).getTimestampMs());
+            Check.isTrue(records.get(0).getError().approxEq(new Vec3(0, 0, 1), 1e-6f), "error vector");
+            Check.equal(20L, records.get(1).getTimestampMs());
+        });
+
+        Check.test("FlightRecorder caps size dropping the oldest", () -> {
+            FlightRecorder recorder = new FlightRecorder(3);
+            for (int i = 0; i < 5; i++){
+                recorder.update(i, Vec3.ZERO, Vec3.ZERO);
+            }
+            Check.equal(3, recorder.size());
+            Check.equal(2L, recorder.getRecords().get(0).getTimestampMs());
+            recorder.clear();
+            Check.equal(0, recorder.size());
+        });
+
+        Check.test("FlightRecorder export layout", () -> {
+            FlightRecorder recorder = new FlightRecorder(4);
+            recorder.update(1234, new Vec3(1, 2, 3), new Vec3(4, 5, 6));
+            byte[] bytes = recorder.export();
+            Check.equal(4 + FlightRecorder.RECORD_LEN, bytes.length);
+            ByteBuffer b = ByteBuffer.wrap(bytes).order(ByteOrder.LITTLE_ENDIAN);
+            Check.equal(1, b.getInt());
+            Check.equal(1234L, b.getLong());
+            for (int i = 1; i <= 6; i++){
+                Check.near(i, b.getFloat(), 0);
+            }
+        });
+
+        Check.test("WaypointNavigator records each update and advances", () -> {
+            WaypointNavigator navigator = new WaypointNavigator();
+            FlightRecorder recorder = new FlightRecorder(16);
+            navigator.setRecorder(recorder);
+            navigator.addWaypoint(new Waypoint(0, 0, 10, 0.5f));
+            navigator.addWaypoint(new Waypoint(10, 0, 10, 0.5f));
+            Check.equal(0f, navigator.update(0, new Vec3(0, 0, 5)).getX());
+            Check.equal(10f, navigator.update(100, new Vec3(0, 0, 9.8f)).getX());
+            Check.equal(null, navigator.update(200, new Vec3(10, 0, 10)));
+            Check.equal(null, navigator.update(300, new Vec3(10, 0, 10)));
+            List<FlightRecorder.Record> records = recorder.getRecords();
+            Check.equal(3, records.size());
+            Check.isTrue(records.get(0).getCommanded().approxEq(new Vec3(0, 0, 10), 0), "first target");
+            Check.isTrue(records.get(2).getCommanded().approxEq(new Vec3(10, 0, 10), 0), "second target");
+            Check.equal(200L, records.get(2).getTimestampMs());
+        });
+    }
+
+    public static void main(String[] args){
+        run();
+        Check.exit();
+    }
+}
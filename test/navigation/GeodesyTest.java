@@ -0,0 +1,85 @@
+package navigation;
+
+import Util.Check;
+import Util.Math3d.Vec3;
+
+public class GeodesyTest {
+    public static void run(){
+        Check.test("Haversine matches known city-pair distances", () -> {
+            GeoPoint london = new GeoPoint(51.5074, -0.1278);
+            GeoPoint paris = new GeoPoint(48.8566, 2.3522);
+            GeoPoint newYork = new GeoPoint(40.7128, -74.0060);
+            GeoPoint losAngeles = new GeoPoint(34.0522, -118.2437);
+            Check.near(343.5e3, Geodesy.distanceM(london, paris), 1e3);
+            Check.near(3936e3, Geodesy.distanceM(newYork, losAngeles), 5e3);
+            Check.near(Geodesy.distanceM(paris, london), Geodesy.distanceM(london, paris), 1e-6);
+            Check.near(0, Geodesy.distanceM(paris, paris), 1e-9);
+        });
+
+        Check.test("Bearing covers all four quadrants", () -> {
+            GeoPoint o = new GeoPoint(0, 0);
+            Check.near(0, Geodesy.bearingDeg(o, new GeoPoint(1, 0)), 1e-9);
+            Check.near(90, Geodesy.bearingDeg(o, new GeoPoint(0, 1)), 1e-9);
+            Check.near(45, Geodesy.bearingDeg(o, new GeoPoint(1, 1)), 0.1);
+            Check.near(135, Geodesy.bearingDeg(o, new GeoPoint(-1, 1)), 0.1);
+            Check.near(225, Geodesy.bearingDeg(o, new GeoPoint(-1, -1)), 0.1);
+            Check.near(315, Geodesy.bearingDeg(o, new GeoPoint(1, -1)), 0.1);
+        });
+
+        Check.test("Destination inverts distance and bearing", () -> {
+            GeoPoint from = new GeoPoint(47.3769, 8.5417, 400);
+            GeoPoint to = Geodesy.destination(from, 123, 25e3);
+            Check.near(25e3, Geodesy.distanceM(from, to), 1e-3);
+            Check.near(123, Geodesy.bearingDeg(from, to), 1e-6);
+            Check.near(400, to.getAltM(), 0);
+        });
+
+        Check.test("Paths across the antimeridian take the short way", () -> {
+            GeoPoint west = new GeoPoint(0, 179.9);
+            GeoPoint east = new GeoPoint(0, -179.9);
+            double expected = Math.toRadians(0.2) * Geodesy.EARTH_RADIUS_M;
+            Check.near(expected, Geodesy.distanceM(west, east), 1e-3);
+            Check.near(90, Geodesy.bearingDeg(west, east), 1e-9);
+            GeoPoint reached = Geodesy.destination(west, 90, expected);
+            Check.near(-179.9, reached.getLonDeg(), 1e-9);
+            Check.near(-180, new GeoPoint(0, 180).getLonDeg(), 0);
+
+            LocalFrame frame = new LocalFrame(new GeoPoint(10, 179.99));
+            Vec3 local = frame.toLocal(new GeoPoint(10, -179.99));
+            Check.isTrue(local.x > 2000 && local.x < 2300, "east of the origin, got " + local.x);
+            Check.near(-179.99, frame.toGeo(local).getLonDeg(), 1e-6);
+        });
+
+        Check.test("LocalFrame round trips within bounds at various latitudes", () -> {
+            double[] lats = {0, 37.5, -45, 60, -75, 88};
+            Vec3[] offsets = {new Vec3(0, 0, 0), new Vec3(100, -250, 12), new Vec3(-5000, 5000, -30), new Vec3(9000, 1000, 0)};
+            for (double lat : lats){
+                LocalFrame frame = new LocalFrame(new GeoPoint(lat, -122.4, 50));
+                for (Vec3 offset : offsets){
+                    GeoPoint geo = frame.toGeo(offset);
+                    Check.isTrue(frame.toLocal(geo).approxEq(offset, 0.01f), "round trip at lat " + lat + " for " + offset);
+                }
+            }
+        });
+
+        Check.test("LocalFrame agrees with haversine near the origin", () -> {
+            GeoPoint origin = new GeoPoint(52.0, 4.3);
+            LocalFrame frame = new LocalFrame(origin);
+            GeoPoint p = Geodesy.destination(origin, 60, 5000);
+            Vec3 local = frame.toLocal(p);
+            Check.near(5000, local.norm(), 5000 * 0.005);
+            Check.near(60, Math.toDegrees(Math.atan2(local.x, local.y)), 0.5);
+        });
+
+        Check.test("LocalFrame refuses origins near the poles", () -> {
+            Check.throwsA(IllegalArgumentException.class, () -> new LocalFrame(new GeoPoint(89.5, 0)));
+            Check.throwsA(IllegalArgumentException.class, () -> new LocalFrame(new GeoPoint(-90, 0)));
+            Check.throwsA(IllegalArgumentException.class, () -> new GeoPoint(91, 0));
+        });
+    }
+
+    public static void main(String[] args){
+        run();
+        Check.exit();
+    }
+}
@@ -0,0 +1,49 @@
+package navigation;
+
+import Util.Check;
+
+import java.nio.ByteBuffer;
+import java.nio.ByteOrder;
+
+public class WaypointNavigatorTest {
+    private static byte[] mission(int count, float... values){
+        ByteBuffer b = ByteBuffer.allocate(4 + values.length * 4).order(ByteOrder.LITTLE_ENDIAN);
+        b.putInt(count);
+        for (float v : values){
+            b.putFloat(v);
+        }
+        return b.array();
+    }
+
+    public static void run(){
+        Check.test("WaypointNavigator loads a multi-waypoint mission", () -> {
+            WaypointNavigator navigator = new WaypointNavigator();
+            navigator.addWaypoint(new Waypoint(0, 0, 0, 1));
+            Check.equal(2, navigator.loadMission(mission(2, 1, 2, 3, 0.5f, 4, 5, 6, 0.25f)));
+            Check.equal(3, navigator.remaining());
+            navigator.advance();
+            Waypoint first = navigator.advance();
+            Check.equal(1f, first.getX());
+            Check.equal(3f, first.getZ());
+            Check.equal(0.5f, first.getTolerance());
+            Check.equal(4f, navigator.currentWaypoint().getX());
+            Check.equal(0, navigator.loadMission(mission(0)));
+        });
+
+        Check.test("WaypointNavigator rejects malformed missions untouched", () -> {
+            WaypointNavigator navigator = new WaypointNavigator();
+            navigator.addWaypoint(new Waypoint(9, 9, 9, 1));
+            Check.throwsA(MissionFormatException.class, () -> navigator.loadMission(mission(2, 1, 2, 3, 0.5f)));
+            Check.throwsA(MissionFormatException.class, () -> navigator.loadMission(mission(1, 1, 2, 3, 0.5f, 7)));
+            Check.throwsA(MissionFormatException.class, () -> navigator.loadMission(new byte[]{1, 0}));
+            Check.throwsA(MissionFormatException.class, () -> navigator.loadMission(mission(-1)));
+            Check.equal(1, navigator.remaining());
+            Check.equal(9f, navigator.currentWaypoint().getX());
+        });
+    }
+
+    public static void main(String[] args){
+        run();
+        Check.exit();
+    }
+}